@@ -0,0 +1,60 @@
+use anyhow::{Context, Result};
+use common::{blocks, Answer, LineContext, Solver};
+use std::collections::BTreeMap;
+
+// How many elves are carrying each total number of calories.
+type Loads = BTreeMap<u32, usize>;
+
+// Sum the calories carried by the |most| laden elves.
+fn sum_most_laden(loads: &Loads, most: usize) -> Result<u32> {
+    let mut sum = 0u32;
+    let mut left = most;
+    for (calories, count) in loads.iter().rev() {
+        let taken = (*count).min(left);
+        sum = u32::try_from(taken)
+            .ok()
+            .and_then(|taken| taken.checked_mul(*calories))
+            .and_then(|calories| sum.checked_add(calories))
+            .context("too many calories to add up")?;
+        left -= taken;
+        if left == 0 {
+            break;
+        }
+    }
+    Ok(sum)
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Parsed = Loads;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Loads> {
+        let mut loads = Loads::new();
+        // Each elf's load is listed as a block of lines.
+        for block in blocks(&lines) {
+            let load = block.numbered().try_fold(0u32, |acc, (index, line)| {
+                let val = line
+                    .parse::<u32>()
+                    .context("invalid input line")
+                    .line_context(index, line)?;
+                acc.checked_add(val)
+                    .context("too many calories for one elf")
+                    .line_context(index, line)
+            })?;
+            loads
+                .entry(load)
+                .and_modify(|count| *count += 1)
+                .or_insert(1);
+        }
+        Ok(loads)
+    }
+
+    fn part_a(&self, loads: &Loads) -> Result<Answer> {
+        Ok(sum_most_laden(loads, 1)?.into())
+    }
+
+    fn part_b(&self, loads: &Loads) -> Result<Answer> {
+        Ok(sum_most_laden(loads, 3)?.into())
+    }
+}
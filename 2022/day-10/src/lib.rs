@@ -0,0 +1,198 @@
+use anyhow::{bail, Context, Result};
+use common::image::{Image, Rgb, BLACK};
+use common::{Answer, LineContext, Solver, Variant};
+
+// Which cycle to start sampling at.
+const START: usize = 20;
+
+// Which cycle to stop the processor at.
+const STOP: usize = 240;
+
+// The gap between samples (and also, conveniently, the width of the screen in pixels).
+const GAP: usize = 40;
+
+// How the CRT's lit pixels look in a picture of it, and how many pixels wide each of them is.
+const CRT_LIT: Rgb = [51, 255, 102];
+const CRT_SCALE: usize = 8;
+
+struct Processor<const START: usize, const STOP: usize, const GAP: usize> {
+    cycles: usize,
+    signal: isize,
+    samples: Vec<isize>,
+}
+
+enum Continue {
+    Yes,
+    No,
+}
+
+pub enum Instruction {
+    Noop,
+    Add(isize),
+}
+
+impl<const START: usize, const STOP: usize, const GAP: usize> Processor<START, STOP, GAP> {
+    fn new() -> Processor<START, STOP, GAP> {
+        Processor::<START, STOP, GAP> {
+            cycles: 0,
+            signal: 1,
+            samples: vec![],
+        }
+    }
+
+    fn noop<F>(&mut self, f: &mut F) -> Result<Continue>
+    where
+        F: FnMut(usize, isize) -> Result<()>,
+    {
+        self.increment_then_sample(1, f)
+    }
+
+    fn add<F>(&mut self, diff: isize, f: &mut F) -> Result<Continue>
+    where
+        F: FnMut(usize, isize) -> Result<()>,
+    {
+        let keep_going = self.increment_then_sample(2, f)?;
+        self.signal = self
+            .signal
+            .checked_add(diff)
+            .context("signal too large to add to")?;
+        Ok(keep_going)
+    }
+
+    fn increment_then_sample<F>(&mut self, inc: usize, f: &mut F) -> Result<Continue>
+    where
+        F: FnMut(usize, isize) -> Result<()>,
+    {
+        for _ in 0..inc {
+            self.cycles += 1;
+            f(self.cycles, self.signal)?;
+        }
+        let want_samples = (self.cycles + START) / GAP;
+        if self.samples.len() < want_samples {
+            self.samples.push(self.signal);
+        }
+        Ok(self.keep_going())
+    }
+
+    fn keep_going(&self) -> Continue {
+        if self.cycles > STOP {
+            return Continue::No;
+        }
+        Continue::Yes
+    }
+}
+
+fn parse_line(line: &str) -> Result<Instruction> {
+    let mut parts = line.split_whitespace();
+    let command = parts.next();
+    match command {
+        None => bail!("parsing: empty line"),
+        Some(command) => match command {
+            "noop" => Ok(Instruction::Noop),
+            "addx" => Ok(Instruction::Add(match parts.next() {
+                None => bail!("parsing: no argument for add command"),
+                Some(operand) => operand.parse()?,
+            })),
+            _ => bail!("parsing: unrecognized command"),
+        },
+    }
+}
+
+fn render<const TOTAL: usize, const WIDTH: usize>(pixels: [bool; TOTAL]) -> Vec<String> {
+    pixels
+        .chunks(WIDTH)
+        .map(|row| {
+            row.iter()
+                .map(|is_set| match is_set {
+                    true => '#',
+                    false => '.',
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Which of the CRT's pixels the |instructions| light up.
+fn draw(instructions: &[Instruction]) -> Result<[bool; STOP]> {
+    let mut processor = Processor::<START, STOP, GAP>::new();
+    let mut pixels = [false; STOP];
+    execute(&mut processor, instructions, &mut |cycle, signal: isize| {
+        // The pixel being drawn is 0-indexed, while cycles start at 1.
+        let pos: usize = (cycle - 1) % GAP;
+        let offset: isize = pos.try_into()?;
+        // A program can run on past the last pixel, which has nothing left to draw.
+        let Some(pixel) = pixels.get_mut(cycle - 1) else {
+            return Ok(());
+        };
+        *pixel = offset.abs_diff(signal) <= 1;
+        Ok(())
+    })?;
+    Ok(pixels)
+}
+
+// Feed each of the |instructions| to the |processor| until it is told to stop.
+fn execute<F>(
+    processor: &mut Processor<START, STOP, GAP>,
+    instructions: &[Instruction],
+    handler: &mut F,
+) -> Result<()>
+where
+    F: FnMut(usize, isize) -> Result<()>,
+{
+    for instruction in instructions {
+        let keep_going = match instruction {
+            Instruction::Noop => processor.noop(handler),
+            Instruction::Add(diff) => processor.add(*diff, handler),
+        };
+        if let Continue::No = keep_going? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Vec<Instruction>> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| parse_line(line.as_str()).line_context(index, line))
+            .collect()
+    }
+
+    fn part_a(&self, instructions: &Vec<Instruction>) -> Result<Answer> {
+        let mut processor = Processor::<START, STOP, GAP>::new();
+        execute(&mut processor, instructions, &mut |_, _| Ok(()))?;
+        Ok(processor
+            .samples
+            .iter()
+            .enumerate()
+            .try_fold(0isize, |acc, (index, signal)| {
+                (START as isize + (index * GAP) as isize)
+                    .checked_mul(*signal)
+                    .and_then(|strength| acc.checked_add(strength))
+            })
+            .context("signal strengths too large to add up")?
+            .into())
+    }
+
+    fn part_b(&self, instructions: &Vec<Instruction>) -> Result<Answer> {
+        Ok(Answer::Render(render::<STOP, GAP>(draw(instructions)?)))
+    }
+
+    // The CRT as it looks once part b is done, lit up in green.
+    fn picture(&self, instructions: &Vec<Instruction>, variant: Variant) -> Result<Image> {
+        if variant == Variant::A {
+            bail!("only part b draws on the CRT");
+        }
+        let image = Image::from_flat(&draw(instructions)?, GAP, |_, is_set| match is_set {
+            true => CRT_LIT,
+            false => BLACK,
+        })?;
+        Ok(image.scaled(CRT_SCALE))
+    }
+}
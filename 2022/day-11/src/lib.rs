@@ -0,0 +1,265 @@
+use anyhow::{bail, Context, Result};
+use common::cycle::Orbit;
+use common::parser::{Cursor, PResult, Record};
+use common::{blocks, Answer, Solver};
+use std::fmt::Debug;
+
+// What we divide an `Item`'s `worry` score by whenever a monkey gets bored with it.
+const BOREDOM_DIVISOR: usize = 3;
+
+#[derive(Clone)]
+struct Item {
+    worry: usize,
+}
+
+impl Debug for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.worry)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Throw {
+    to: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operand {
+    Old,
+    Num(usize),
+}
+
+#[derive(Clone, Debug)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+#[derive(Clone, Debug)]
+struct Op {
+    operands: [Operand; 2],
+    operator: Operator,
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    /// The `Item`s this `Monkey` starts out holding.
+    items: Vec<Item>,
+
+    /// The pre-test operation to perform on the `Item`'s `worry` score.
+    op: Op,
+
+    /// How much we divide an `Item` by when testing it.
+    test_using: usize,
+
+    /// Which `Monkey` we throw the `Item` to if the test passes.
+    if_true: Throw,
+
+    /// Which `Monkey` we throw the `Item` to if the test fails.
+    if_false: Throw,
+}
+
+fn parse_operand(c: &mut Cursor) -> PResult<Operand> {
+    match c.eat("old") {
+        true => Ok(Operand::Old),
+        false => Ok(Operand::Num(c.integer()?)),
+    }
+}
+
+fn parse_throw(c: &mut Cursor) -> PResult<Throw> {
+    c.literal("throw to monkey")?;
+    Ok(Throw { to: c.integer()? })
+}
+
+fn parse_monkey(mut record: Record) -> PResult<Monkey> {
+    // Monkeys are listed in order, so their numbers don't need to be kept.
+    record.line(|c| {
+        c.literal("Monkey")?;
+        c.integer::<usize>()?;
+        c.literal(":")
+    })?;
+    let items = record.field("Starting items", |c| {
+        c.separated(",", |c| {
+            Ok(Item {
+                worry: c.integer()?,
+            })
+        })
+    })?;
+    let op = record.field("Operation", |c| {
+        c.literal("new")?;
+        c.literal("=")?;
+        let first = parse_operand(c)?;
+        let operator = c.one_of(&[("+", Operator::Add), ("*", Operator::Multiply)])?;
+        Ok(Op {
+            operands: [first, parse_operand(c)?],
+            operator,
+        })
+    })?;
+    let test_using = record.field("Test", |c| {
+        c.literal("divisible by")?;
+        match c.integer()? {
+            0 => Err(c.error("expected a divisor other than 0")),
+            divisor => Ok(divisor),
+        }
+    })?;
+    let if_true = record.field("If true", parse_throw)?;
+    let if_false = record.field("If false", parse_throw)?;
+    record.end()?;
+
+    Ok(Monkey {
+        items,
+        op,
+        test_using,
+        if_true,
+        if_false,
+    })
+}
+
+fn parse(lines: Vec<String>) -> Result<Vec<Monkey>> {
+    let monkeys = blocks(&lines)
+        .map(|block| parse_monkey(Record::new(&block)))
+        .collect::<PResult<Vec<_>>>()?;
+    Ok(monkeys)
+}
+
+/// Where an item is at the start of a round: who holds it, and how worried we are about it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Whereabouts {
+    holder: usize,
+    worry: usize,
+}
+
+/// How our worry about an item is kept in check after each inspection.
+#[derive(Clone, Copy, Debug)]
+pub enum Relief {
+    /// We're relieved the item wasn't damaged, dividing our worry by |BOREDOM_DIVISOR|.
+    Bored,
+
+    /// We keep our worry modulo the given number, which must be a multiple of every monkey's
+    /// test divisor so that none of the tests are affected.
+    Managed(usize),
+}
+
+impl Relief {
+    /// Manage our worry by the product of the monkeys' test divisors.
+    fn managed(monkeys: &[Monkey]) -> Result<Relief> {
+        let modulus = monkeys
+            .iter()
+            .try_fold(1usize, |modulus, monkey| {
+                modulus.checked_mul(monkey.test_using)
+            })
+            .context("product of the test divisors overflowed")?;
+        Ok(Relief::Managed(modulus))
+    }
+}
+
+impl Monkey {
+    /// Inspect an item with the given |worry|, returning the item's new worry and who it's thrown
+    /// to, or None if we get too worried to keep count.
+    fn inspect(&self, worry: usize, relief: Relief) -> Option<(usize, usize)> {
+        let [a, b] = self.op.operands.map(|operand| match operand {
+            Operand::Old => worry,
+            Operand::Num(num) => num,
+        });
+        let worry = match self.op.operator {
+            Operator::Add => a.checked_add(b)?,
+            Operator::Multiply => a.checked_mul(b)?,
+        };
+        let worry = match relief {
+            Relief::Bored => worry / BOREDOM_DIVISOR,
+            Relief::Managed(modulus) => worry % modulus,
+        };
+        let to = match worry.is_multiple_of(self.test_using) {
+            true => self.if_true.to,
+            false => self.if_false.to,
+        };
+        Some((worry, to))
+    }
+}
+
+/// Follow an |item| through a round, calling |visit| with each monkey that inspects it.
+fn follow_round(
+    monkeys: &[Monkey],
+    relief: Relief,
+    mut item: Whereabouts,
+    mut visit: impl FnMut(usize),
+) -> Result<Whereabouts> {
+    loop {
+        visit(item.holder);
+        let (worry, to) = monkeys[item.holder]
+            .inspect(item.worry, relief)
+            .context("worry level overflowed")?;
+        let thrown = Whereabouts { holder: to, worry };
+
+        // Monkeys take their turns in order, so an item thrown to one yet to take its turn is
+        // inspected again in the same round.
+        if to < item.holder {
+            return Ok(thrown);
+        }
+        item = thrown;
+    }
+}
+
+/// Count how many items each monkey inspects over the given number of |rounds|. Items are passed
+/// around independently of each other, so each one is followed on its own, and only until it's
+/// back where it was at the start of an earlier round. From then on its path repeats, which with
+/// |Relief::Managed| is bound to happen sooner or later.
+pub fn count_inspections(monkeys: &[Monkey], rounds: usize, relief: Relief) -> Result<Vec<usize>> {
+    for (index, monkey) in monkeys.iter().enumerate() {
+        for throw in [&monkey.if_true, &monkey.if_false] {
+            if throw.to == index || throw.to >= monkeys.len() {
+                bail!("monkey {} can't throw to monkey {}", index, throw.to);
+            }
+        }
+    }
+
+    let mut inspections = vec![0; monkeys.len()];
+    for (holder, monkey) in monkeys.iter().enumerate() {
+        for item in &monkey.items {
+            let start = Whereabouts {
+                holder,
+                worry: item.worry,
+            };
+            let step = |item: &Whereabouts| follow_round(monkeys, relief, *item, |_| {});
+            let orbit = Orbit::try_trace(start, step, rounds)?;
+            for (item, count) in orbit.occurrences(rounds) {
+                follow_round(monkeys, relief, *item, |monkey| {
+                    inspections[monkey] += count
+                })?;
+            }
+        }
+    }
+    Ok(inspections)
+}
+
+/// The number of rounds we track the monkeys for before doing calculations, depending on how our
+/// worry is relieved.
+const BORED_ROUNDS: usize = 20;
+const MANAGED_ROUNDS: usize = 10_000;
+
+/// The product of the two highest inspection counts.
+fn monkey_business(mut inspections: Vec<usize>) -> usize {
+    inspections.sort();
+    inspections.iter().rev().take(2).product()
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Parsed = Vec<Monkey>;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Vec<Monkey>> {
+        parse(lines)
+    }
+
+    fn part_a(&self, monkeys: &Vec<Monkey>) -> Result<Answer> {
+        let inspections = count_inspections(monkeys, BORED_ROUNDS, Relief::Bored)?;
+        Ok(monkey_business(inspections).into())
+    }
+
+    fn part_b(&self, monkeys: &Vec<Monkey>) -> Result<Answer> {
+        let relief = Relief::managed(monkeys)?;
+        let inspections = count_inspections(monkeys, MANAGED_ROUNDS, relief)?;
+        Ok(monkey_business(inspections).into())
+    }
+}
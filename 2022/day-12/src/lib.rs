@@ -0,0 +1,113 @@
+use anyhow::{bail, Context, Result};
+use common::grid::{Grid, Pos};
+use common::image::{blend, Image, Rgb};
+use common::search::bfs;
+use common::{Answer, Solver, Variant};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Description {
+    grid: Grid<i8>,
+    from: Pos,
+    goal: Pos,
+    mins: HashSet<Pos>,
+}
+
+fn parse(lines: Vec<String>) -> Result<Description> {
+    let mut from = None;
+    let mut goal = None;
+    let mut mins = HashSet::new();
+    let grid = Grid::parse(&lines, |pos, ch| match ch {
+        'S' => match from {
+            Some(_) => bail!("parsing: multiple S cells"),
+            None => {
+                from = Some(pos);
+                mins.insert(pos);
+                Ok(0) // aka "a"
+            }
+        },
+        'E' => match goal {
+            Some(_) => bail!("parsing: multiple E cells"),
+            None => {
+                goal = Some(pos);
+                Ok(25) // aka "z"
+            }
+        },
+        _ => {
+            // Must be a lower-case letter - we can use the ASCII table to verify.
+            let ascii = ch as i8;
+            if !(97..=122).contains(&ascii) {
+                bail!("parsing: unknown character (not lowercase letter, S, or E)")
+            }
+            if ascii == 97 {
+                mins.insert(pos);
+            }
+            Ok(ascii - 97)
+        }
+    })?;
+
+    Ok(Description {
+        from: from.context("parsing: no S cell seen")?,
+        goal: goal.context("parsing: no E cell seen")?,
+        grid,
+        mins,
+    })
+}
+
+// The shortest path from any of the |starts| to the goal, where each step can climb at most one
+// level but descend any number.
+fn shortest_path(desc: &Description, starts: impl IntoIterator<Item = Pos>) -> Result<Vec<Pos>> {
+    let grid = &desc.grid;
+    let climbable = |&pos: &Pos| {
+        grid.neighbours4(pos)
+            .filter(move |&next| grid[next] - grid[pos] <= 1)
+    };
+    bfs(starts, climbable, |pos| pos == &desc.goal)
+        .path()
+        .context("no path reaches the E cell")
+}
+
+// The fewest steps along the |shortest_path|.
+fn fewest_steps(desc: &Description, starts: impl IntoIterator<Item = Pos>) -> Result<usize> {
+    Ok(shortest_path(desc, starts)?.len() - 1)
+}
+
+// How the heightmap is coloured in a picture, from the lowest to the highest ground, and how many
+// pixels wide each square of it is.
+const LOWEST: Rgb = [32, 64, 160];
+const HIGHEST: Rgb = [240, 240, 240];
+const PATH: Rgb = [220, 32, 32];
+const SQUARE_SCALE: usize = 4;
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Parsed = Description;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Description> {
+        parse(lines)
+    }
+
+    fn part_a(&self, desc: &Description) -> Result<Answer> {
+        Ok(fewest_steps(desc, [desc.from])?.into())
+    }
+
+    fn part_b(&self, desc: &Description) -> Result<Answer> {
+        Ok(fewest_steps(desc, desc.mins.iter().copied())?.into())
+    }
+
+    // The heightmap, with the path each part finds drawn over it.
+    fn picture(&self, desc: &Description, variant: Variant) -> Result<Image> {
+        let path: HashSet<Pos> = match variant {
+            Variant::A => shortest_path(desc, [desc.from])?,
+            Variant::B => shortest_path(desc, desc.mins.iter().copied())?,
+        }
+        .into_iter()
+        .collect();
+        let image = Image::from_grid(&desc.grid, |pos, &height| match path.contains(&pos) {
+            true => PATH,
+            false => blend(LOWEST, HIGHEST, height as usize, 25),
+        });
+        Ok(image.scaled(SQUARE_SCALE))
+    }
+}
@@ -0,0 +1,237 @@
+use anyhow::{bail, Context, Result};
+use common::{blocks, Answer, Normalize, ParseError, Solver};
+use std::{
+    cmp::{Ordering, PartialOrd},
+    str::Chars,
+};
+
+// How deeply lists can nest, which is far deeper than any real input goes.
+const MAX_DEPTH: usize = 1_000;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Item {
+    Num(Integer),
+    List(List),
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self {
+            Item::Num(self_num) => match other {
+                Item::Num(other_num) => self_num.cmp(other_num),
+                Item::List(other_list) => List {
+                    items: vec![Item::Num(*self_num)],
+                }
+                .cmp(other_list),
+            },
+            Item::List(self_list) => match other {
+                Item::Num(other_num) => self_list.cmp(&List {
+                    items: vec![Item::Num(*other_num)],
+                }),
+                Item::List(other_list) => self_list.cmp(other_list),
+            },
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Integer {
+    value: u32,
+}
+
+impl Ord for Integer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.value == other.value {
+            Ordering::Equal
+        } else if self.value < other.value {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+impl PartialOrd for Integer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct List {
+    items: Vec<Item>,
+}
+
+impl Ord for List {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Items are compared in turn, and a list that runs out first is the lesser.
+        self.items.cmp(&other.items)
+    }
+}
+
+impl PartialOrd for List {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Parses a list whose opening bracket is |depth| brackets deep, having just consumed it.
+fn parse_list(chars: &mut Chars, depth: usize) -> Result<List> {
+    if depth > MAX_DEPTH {
+        bail!("parsing: lists nested too deeply")
+    }
+    let mut out = List::default();
+    let mut last_was_digit = false;
+    let mut c = chars.next();
+    while c.is_some() {
+        let ch = c.unwrap();
+        if ch.is_ascii_digit() {
+            let digit = ch
+                .to_digit(10)
+                .context("parsing: unable to convert validated digit")?;
+            if !last_was_digit {
+                out.items.push(Item::Num(Integer { value: digit }))
+            } else {
+                match out
+                    .items
+                    .last_mut()
+                    .context("parsing: cannot modify non-existent number")?
+                {
+                    Item::List(_) => bail!("parsing: expected to modify digit"),
+                    Item::Num(num) => {
+                        num.value = num
+                            .value
+                            .checked_mul(10)
+                            .and_then(|value| value.checked_add(digit))
+                            .context("parsing: number too large")?;
+                    }
+                }
+            }
+            last_was_digit = true;
+        } else {
+            last_was_digit = false;
+            match ch {
+                '[' => {
+                    out.items.push(Item::List(parse_list(chars, depth + 1)?));
+                }
+                ']' => return Ok(out),
+                ',' => {}
+                _ => bail!("parsing: invalid character"),
+            }
+        }
+        c = chars.next();
+    }
+    bail!("parsing: all open brackets should have a matching closing pair")
+}
+
+// Parses the list on the line at |index| in the input.
+fn parse_line(index: usize, line: &str) -> Result<List> {
+    let mut chars = line.chars();
+    if chars
+        .next()
+        .context("parsing: list line with no opening character")?
+        != '['
+    {
+        bail!(ParseError::new("parsing: list line with no opening bracket", index, line).at(0))
+    }
+    parse_list(&mut chars, 1).map_err(|err| {
+        // Parsing stops on the character at fault, so blame the last one consumed.
+        let consumed = line.len() - chars.as_str().len();
+        let column = line[..consumed].chars().count() - 1;
+        ParseError::new(err, index, line).at(column).into()
+    })
+}
+
+// Each pair of lists to compare is given as a block of two lines.
+fn parse_pairs(lines: Vec<String>) -> Result<Vec<(List, List)>> {
+    blocks(&lines)
+        .map(|block| match block.lines.as_slice() {
+            [left, right] => Ok((
+                parse_line(block.start, left)?,
+                parse_line(block.start + 1, right)?,
+            )),
+            [_, _, third, ..] => bail!(ParseError::new(
+                "parsing: three consecutive lists not allowed",
+                block.start + 2,
+                third
+            )),
+            // Blocks are never empty, so this is a lone list.
+            _ => bail!(ParseError::new(
+                "parsing: only one list provided for comparison",
+                block.start,
+                block.lines[0]
+            )),
+        })
+        .collect()
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Parsed = Vec<(List, List)>;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Vec<(List, List)>> {
+        parse_pairs(lines)
+    }
+
+    fn part_a(&self, pairs: &Vec<(List, List)>) -> Result<Answer> {
+        // Pairs are 1-indexed in the prompt.
+        Ok(pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| left < right)
+            .map(|(index, _)| index + 1)
+            .sum::<usize>()
+            .into())
+    }
+
+    fn part_b(&self, pairs: &Vec<(List, List)>) -> Result<Answer> {
+        let mut parsed = pairs
+            .iter()
+            .flat_map(|(left, right)| [left.clone(), right.clone()])
+            .collect::<Vec<_>>();
+        parsed.push(List {
+            items: vec![Item::List(List {
+                items: vec![Item::Num(Integer { value: 2 })],
+            })],
+        });
+        parsed.push(List {
+            items: vec![Item::List(List {
+                items: vec![Item::Num(Integer { value: 6 })],
+            })],
+        });
+        parsed.sort();
+
+        let mut start = None;
+        let mut end = None;
+        for (index, outer) in parsed.iter().enumerate() {
+            if outer.items.len() == 1 {
+                if let Item::List(inner) = &outer.items[0] {
+                    if inner.items.len() == 1 {
+                        if let Item::Num(int) = &inner.items[0] {
+                            if int.value == 2 {
+                                start = Some(index + 1)
+                            } else if int.value == 6 {
+                                end = Some(index + 1)
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok((start.context("no decoder key start")? * end.context("no decoder key end")?).into())
+    }
+
+    // Blank lines are what separate the pairs, so they have to be truly empty.
+    fn normalize(&self) -> Option<Normalize> {
+        Some(Normalize {
+            trim_trailing_whitespace: true,
+        })
+    }
+}
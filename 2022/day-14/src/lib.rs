@@ -0,0 +1,327 @@
+use anyhow::{bail, Context, Result};
+use common::grid::{Grid, Pos, DOWN, LEFT, RIGHT};
+use common::image::{Image, Rgb};
+use common::parser::{self, Cursor, PResult};
+use common::viz::Scene;
+use common::{Answer, Solver, Variant};
+use std::fmt::{self, Display};
+
+#[derive(Debug)]
+struct Point {
+    row: usize,
+    col: usize,
+}
+
+#[derive(Debug)]
+pub struct BoundingBox {
+    top_left: Point,
+    bottom_right: Point,
+}
+
+#[derive(Debug)]
+pub struct Multiline {
+    points: Vec<Point>,
+}
+
+/// A tri-state to store the state of a given grid cell. We use this instead of a boolean for
+/// rendering purposes.
+#[derive(Clone, Copy, Debug, Default)]
+enum CellState {
+    Rock,
+    Sand,
+    #[default]
+    Empty,
+}
+
+impl Display for CellState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellState::Rock => write!(f, "#"),
+            CellState::Sand => write!(f, "o"),
+            CellState::Empty => write!(f, "."),
+        }
+    }
+}
+
+/// The part of the cave the sand can reach, along with where in it the sand flows from.
+#[derive(Debug)]
+struct Cave {
+    source: Pos,
+    grid: Grid<CellState>,
+}
+
+/// The point from which sand flows, as defined by the prompt.
+const DEFAULT_SAND_SOURCE: Point = Point { row: 0, col: 500 };
+
+/// The deepest cave that will be filled, which is far deeper than any real input's cave but keeps
+/// the grid, and the time taken to fill it, within reason.
+const MAX_DEPTH: usize = 1_000;
+
+/// Parse a single point, given as `col,row`.
+fn parse_point(c: &mut Cursor) -> PResult<Point> {
+    let col = c.integer()?;
+    c.literal(",")?;
+    Ok(Point {
+        row: c.integer()?,
+        col,
+    })
+}
+
+/// Parse the input into a set of |Multiline|s, expanding of the |BoundingBox| as we go.
+fn parse(lines: Vec<String>) -> Result<(Vec<Multiline>, BoundingBox)> {
+    let mut bounds = BoundingBox {
+        top_left: DEFAULT_SAND_SOURCE,
+        bottom_right: DEFAULT_SAND_SOURCE,
+    };
+    let mut multilines = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        let points = parser::line(index, line, |c| c.separated("->", parse_point))?;
+
+        // Update the bounds if we have exceeded them in any direction.
+        for Point { row, col } in &points {
+            bounds.top_left.row = bounds.top_left.row.min(*row);
+            bounds.bottom_right.row = bounds.bottom_right.row.max(*row);
+            bounds.top_left.col = bounds.top_left.col.min(*col);
+            bounds.bottom_right.col = bounds.bottom_right.col.max(*col);
+        }
+        multilines.push(Multiline { points });
+    }
+
+    Ok((multilines, bounds))
+}
+
+/// Create the starting state for the cave by filling in each point touched by a line. The |floor|
+/// argument controls how much the floor is offset by at the bottom of the grid.
+fn init_cave(multilines: &[Multiline], bounds: &BoundingBox, floor: Option<usize>) -> Result<Cave> {
+    let height = (bounds.bottom_right.row - bounds.top_left.row)
+        .checked_add(1 + floor.unwrap_or(0))
+        .filter(|&height| height <= MAX_DEPTH)
+        .context("init: cave too deep to fill")?;
+    let width = height * 2;
+    let row_offset = bounds.top_left.row;
+    let mut grid = Grid::new(width, height, CellState::default());
+
+    // The grid is only as wide as the sand can spread, centred on the source, so rock outside of
+    // these columns can be left out as no sand will ever land on it.
+    let leftmost = DEFAULT_SAND_SOURCE.col.saturating_sub(width / 2);
+    let rightmost = DEFAULT_SAND_SOURCE.col + width / 2 - 1;
+    for multiline in multilines {
+        if multiline.points.len() <= 1 {
+            bail!("init: multiline must have at least two points");
+        }
+        for ends in multiline.points.windows(2) {
+            let (from, to) = (&ends[0], &ends[1]);
+            if from.row != to.row && from.col != to.col {
+                bail!("init: multiline segment not straight");
+            }
+            let cols = from.col.min(to.col).max(leftmost)..=from.col.max(to.col).min(rightmost);
+            for row in from.row.min(to.row)..=from.row.max(to.row) {
+                for col in cols.clone() {
+                    let col = col + width / 2 - DEFAULT_SAND_SOURCE.col;
+                    grid[Pos::new(row - row_offset, col)] = CellState::Rock;
+                }
+            }
+        }
+    }
+
+    // Before exiting, add the floor, if requested.
+    if floor.is_some() {
+        for col in 0..width {
+            grid[Pos::new(height - 1, col)] = CellState::Rock;
+        }
+    }
+    Ok(Cave {
+        source: Pos::new(DEFAULT_SAND_SOURCE.row - bounds.top_left.row, width / 2),
+        grid,
+    })
+}
+
+/// What became of the falling grain of sand after a single step of the simulation.
+enum Flow {
+    Fell,
+    Settled,
+    // The grain settled at the source, so no more sand can enter the cave.
+    Plugged,
+    // The grain left the part of the cave we track, as will every grain after it.
+    Escaped,
+}
+
+/// Move the grain of sand at |pos| on by a single cell, or settle it where it is and start the next
+/// grain at the source.
+fn flow(cave: &mut Cave, pos: &mut Pos) -> Flow {
+    let grid = &mut cave.grid;
+
+    // Try going down...
+    let Some(down_pos) = grid.step(*pos, DOWN) else {
+        // We've fallen off the bottom.
+        return Flow::Escaped;
+    };
+    if let CellState::Empty = grid[down_pos] {
+        *pos = down_pos;
+        return Flow::Fell;
+    }
+
+    // ...then down and left...
+    let Some(left_and_down_pos) = grid.step(down_pos, LEFT) else {
+        // We've reached the left edge of the grid.
+        return Flow::Escaped;
+    };
+    if let CellState::Empty = grid[left_and_down_pos] {
+        *pos = left_and_down_pos;
+        return Flow::Fell;
+    }
+
+    // ...then, finally, down and right.
+    let Some(right_and_down_pos) = grid.step(down_pos, RIGHT) else {
+        // We've reached the right edge of the grid.
+        return Flow::Escaped;
+    };
+    if let CellState::Empty = grid[right_and_down_pos] {
+        *pos = right_and_down_pos;
+        return Flow::Fell;
+    }
+
+    // If we're stuck, place the grain.
+    grid[*pos] = CellState::Sand;
+    if *pos == cave.source {
+        return Flow::Plugged;
+    }
+
+    // Make sure to reset the starting position for each new grain.
+    *pos = cave.source;
+    Flow::Settled
+}
+
+/// Iteratively add grains of sand until the pile is cannot accept any more. Record the number of
+/// grains needed to achieve this end state.
+fn simulate_until_full(cave: &mut Cave) -> Result<usize> {
+    let mut pos = cave.source;
+    let mut count = 0;
+    loop {
+        match flow(cave, &mut pos) {
+            Flow::Fell => {}
+            Flow::Settled => count += 1,
+            Flow::Plugged => return Ok(count + 1),
+            Flow::Escaped => return Ok(count),
+        }
+    }
+}
+
+/// Fill a fresh copy of the cave for |variant| with sand, returning the cave and the number of
+/// grains it took. Part b has a floor two rows below the lowest rock.
+fn fill(
+    (multilines, bounds): &(Vec<Multiline>, BoundingBox),
+    variant: Variant,
+) -> Result<(Cave, usize)> {
+    let mut cave = init_cave(multilines, bounds, floor(variant))?;
+    let grains = simulate_until_full(&mut cave)?;
+    Ok((cave, grains))
+}
+
+fn floor(variant: Variant) -> Option<usize> {
+    match variant {
+        Variant::A => None,
+        Variant::B => Some(2),
+    }
+}
+
+// How each state of cell is coloured in a picture of the cave, and how many pixels wide each
+// cell is.
+const ROCK: Rgb = [96, 96, 96];
+const SAND: Rgb = [230, 190, 90];
+const AIR: Rgb = [20, 20, 36];
+const CELL_SCALE: usize = 4;
+
+/// The sand pouring into the cave, one cell of a grain's fall at a time.
+struct Pouring {
+    cave: Cave,
+    // Where the grain that's falling has got to, or None once the sand has stopped.
+    falling: Option<Pos>,
+    grains: usize,
+}
+
+impl Scene for Pouring {
+    fn step(&mut self) -> Result<bool> {
+        let Some(pos) = &mut self.falling else {
+            return Ok(false);
+        };
+        match flow(&mut self.cave, pos) {
+            Flow::Fell => {}
+            Flow::Settled => self.grains += 1,
+            Flow::Plugged => {
+                self.grains += 1;
+                self.falling = None;
+            }
+            Flow::Escaped => self.falling = None,
+        }
+        Ok(true)
+    }
+
+    /// Draw the cave below the point the sand enters it, with the falling grain as `~`.
+    fn frame(&self) -> String {
+        let grid = &self.cave.grid;
+        let mut rows = vec![(0..grid.width())
+            .map(|col| {
+                if self.cave.source.col == col {
+                    '|'
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>()];
+        for (row, cells) in grid.rows().enumerate() {
+            let mut text = String::new();
+            for (col, cell) in cells.iter().enumerate() {
+                match self.falling {
+                    Some(pos) if pos == Pos::new(row, col) => text.push('~'),
+                    _ => text.push_str(&cell.to_string()),
+                }
+            }
+            rows.push(text);
+        }
+        rows.push(format!("\n{} grains of sand at rest", self.grains));
+        rows.join("\n")
+    }
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Parsed = (Vec<Multiline>, BoundingBox);
+
+    fn parse(&self, lines: Vec<String>) -> Result<(Vec<Multiline>, BoundingBox)> {
+        parse(lines)
+    }
+
+    fn part_a(&self, parsed: &(Vec<Multiline>, BoundingBox)) -> Result<Answer> {
+        Ok(fill(parsed, Variant::A)?.1.into())
+    }
+
+    fn part_b(&self, parsed: &(Vec<Multiline>, BoundingBox)) -> Result<Answer> {
+        Ok(fill(parsed, Variant::B)?.1.into())
+    }
+
+    fn scene<'a>(
+        &self,
+        (multilines, bounds): &'a (Vec<Multiline>, BoundingBox),
+        variant: Variant,
+    ) -> Result<Box<dyn Scene + 'a>> {
+        let cave = init_cave(multilines, bounds, floor(variant))?;
+        Ok(Box::new(Pouring {
+            falling: Some(cave.source),
+            cave,
+            grains: 0,
+        }))
+    }
+
+    /// The pile of sand once no more will come to rest.
+    fn picture(&self, parsed: &(Vec<Multiline>, BoundingBox), variant: Variant) -> Result<Image> {
+        let (cave, _) = fill(parsed, variant)?;
+        let image = Image::from_grid(&cave.grid, |_, cell| match cell {
+            CellState::Rock => ROCK,
+            CellState::Sand => SAND,
+            CellState::Empty => AIR,
+        });
+        Ok(image.scaled(CELL_SCALE))
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::{bail, Context, Result};
+use common::{Answer, LineContext, Solver};
+
+enum Outcome {
+    Win,
+    Draw,
+    Lose,
+}
+
+impl Outcome {
+    fn from_string(code: &str) -> Result<Outcome> {
+        match code {
+            "Z" => Ok(Outcome::Win),
+            "Y" => Ok(Outcome::Draw),
+            "X" => Ok(Outcome::Lose),
+            _ => bail!("invalid input code"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Throw {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Throw {
+    fn from_string(code: &str) -> Result<Throw> {
+        match code {
+            "A" | "X" => Ok(Throw::Rock),
+            "B" | "Y" => Ok(Throw::Paper),
+            "C" | "Z" => Ok(Throw::Scissors),
+            _ => bail!("invalid input code"),
+        }
+    }
+
+    fn versus(&self, against: Throw) -> u32 {
+        match self {
+            Throw::Rock => match against {
+                Throw::Rock => 4,
+                Throw::Paper => 1,
+                Throw::Scissors => 7,
+            },
+            Throw::Paper => match against {
+                Throw::Rock => 8,
+                Throw::Paper => 5,
+                Throw::Scissors => 2,
+            },
+            Throw::Scissors => match against {
+                Throw::Rock => 3,
+                Throw::Paper => 9,
+                Throw::Scissors => 6,
+            },
+        }
+    }
+
+    fn cheat(&self, want_outcome: Outcome) -> u32 {
+        match self {
+            // To "cheat" against rock, we need to throw paper to win, and scissors to lose.
+            Throw::Rock => match want_outcome {
+                Outcome::Draw => 4,
+                Outcome::Win => 8,
+                Outcome::Lose => 3,
+            },
+            // To "cheat" against paper, we need to throw scissors to win, and rock to lose.
+            Throw::Paper => match want_outcome {
+                Outcome::Draw => 5,
+                Outcome::Win => 9,
+                Outcome::Lose => 1,
+            },
+            // To "cheat" against scissors, we need to throw rock to win, and paper to lose.
+            Throw::Scissors => match want_outcome {
+                Outcome::Draw => 6,
+                Outcome::Win => 7,
+                Outcome::Lose => 2,
+            },
+        }
+    }
+}
+
+// A single line of the strategy guide. What the |code| column means depends on the variant.
+pub struct Round {
+    them: Throw,
+    code: String,
+}
+
+fn parse_round(line: &str) -> Result<Round> {
+    let mut strategy = line.split_whitespace();
+    let them = Throw::from_string(strategy.next().context("missing their throw")?)?;
+    let code = strategy.next().context("missing second column")?;
+    Ok(Round {
+        them,
+        code: code.to_string(),
+    })
+}
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Parsed = Vec<Round>;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Vec<Round>> {
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, line)| parse_round(line.as_str()).line_context(index, line))
+            .collect()
+    }
+
+    fn part_a(&self, rounds: &Vec<Round>) -> Result<Answer> {
+        let sum = rounds.iter().try_fold(0u32, |acc, round| {
+            let me = Throw::from_string(round.code.as_str()).context("invalid throw")?;
+            Ok::<u32, anyhow::Error>(acc + me.versus(round.them))
+        })?;
+        Ok(sum.into())
+    }
+
+    fn part_b(&self, rounds: &Vec<Round>) -> Result<Answer> {
+        let sum = rounds.iter().try_fold(0u32, |acc, round| {
+            let want_outcome =
+                Outcome::from_string(round.code.as_str()).context("invalid desired outcome")?;
+            Ok::<u32, anyhow::Error>(acc + round.them.cheat(want_outcome))
+        })?;
+        Ok(sum.into())
+    }
+}
@@ -0,0 +1,98 @@
+#![feature(iter_array_chunks)]
+
+use anyhow::{bail, Error, Result};
+use common::{Answer, Solver};
+use std::collections::{HashMap, HashSet};
+
+// Score a letter by priority.
+fn char_to_priority(ch: char) -> Result<u32> {
+    if ch.is_lowercase() {
+        // "a" is the decimal value 97 in ASCII; to make it or any other
+        // lower case letters equal 1..=26, we need to subtract 96.
+        Ok(ch as u32 - 96)
+    } else if ch.is_uppercase() {
+        // "A" is the decimal value 65 in ASCII; to make it or any other
+        // upper case letters equal 27..=52, we need to subtract 38.
+        Ok(ch as u32 - 38)
+    } else {
+        bail!("invalid item in rucksack")
+    }
+}
+
+// Find the item common to both the first half and second half of the input list.
+fn get_overlapping_item_priority(line: &str) -> Result<u32> {
+    let mut seen = HashSet::<u32>::new();
+    let count = line.len() / 2;
+    if !line.len().is_multiple_of(2) {
+        bail!("each rucksack must have an even number of items")
+    }
+
+    for (index, ch) in line.chars().enumerate() {
+        if index < count {
+            seen.insert(char_to_priority(ch)?);
+        } else {
+            if let Some(priority) = seen.get(&char_to_priority(ch)?) {
+                return Ok(*priority);
+            }
+        }
+    }
+    bail!("no duplicate item found")
+}
+
+// Find the item that occurs in each of the three lines in the |triplet| provided.
+fn get_common_item_priority(triplet: [&String; 3]) -> Result<u32> {
+    let mut counts = HashMap::<char, u32>::new();
+    for line in triplet {
+        if line.is_empty() {
+            bail!("encountered empty line")
+        }
+
+        // Use the "seen" set to ensure that we don't count an item multiple times per line.
+        let mut seen = HashSet::<char>::new();
+        line.chars().for_each(|ch| {
+            if !seen.contains(&ch) {
+                seen.insert(ch);
+                counts
+                    .entry(ch)
+                    .and_modify(|count| {
+                        *count += 1;
+                    })
+                    .or_insert(1);
+            }
+        })
+    }
+    match counts.into_iter().find(|(_, count)| *count == 3) {
+        Some((ch, _)) => char_to_priority(ch),
+        None => bail!("no common item found"),
+    }
+}
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Vec<String>> {
+        Ok(lines)
+    }
+
+    fn part_a(&self, lines: &Vec<String>) -> Result<Answer> {
+        let sum = lines.iter().try_fold(0u32, |acc, line| {
+            if !line.is_empty() {
+                return Ok(acc + get_overlapping_item_priority(line.as_str())?);
+            }
+            bail!("encountered empty line")
+        })?;
+        Ok(sum.into())
+    }
+
+    fn part_b(&self, lines: &Vec<String>) -> Result<Answer> {
+        let sum = lines
+            .iter()
+            .array_chunks::<3>()
+            .try_fold(0u32, |acc, triplet| {
+                Ok::<u32, Error>(acc + get_common_item_priority(triplet)?)
+            })?;
+        Ok(sum.into())
+    }
+}
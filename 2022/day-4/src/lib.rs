@@ -0,0 +1,85 @@
+use anyhow::{bail, Context, Result};
+use common::{Answer, LineContext, Solver};
+
+// Assumes two ranges, each with two digits.
+fn superset(ranges: &[Vec<u32>]) -> bool {
+    let mut ranges = ranges.to_vec();
+    ranges[0].sort();
+    ranges[1].sort();
+    ranges.sort();
+    let lower = &ranges[0];
+    let upper = &ranges[1];
+    if upper[0] == lower[0] || upper[1] <= lower[1] {
+        return true;
+    }
+    false
+}
+
+// Assumes two ranges, each with two digits.
+fn intersect(ranges: &[Vec<u32>]) -> bool {
+    let mut ranges = ranges.to_vec();
+    ranges[0].sort();
+    ranges[1].sort();
+    ranges.sort();
+    let lower = &ranges[0];
+    let upper = &ranges[1];
+    if lower[1] >= upper[0] {
+        return true;
+    }
+    false
+}
+
+// Each pair of elves' assignments, as two ranges of two digits.
+type Pairs = Vec<Vec<Vec<u32>>>;
+
+// Parses a line like `2-4,6-8` into its two ranges.
+fn parse_pair(line: &str) -> Result<Vec<Vec<u32>>> {
+    let ranges = line
+        .split(',')
+        .map(|range| {
+            let nums = range
+                .split('-')
+                .map(|num| num.parse::<u32>().context("invalid section number"))
+                .collect::<Result<Vec<_>>>()?;
+            if nums.len() != 2 {
+                bail!("each range must have a start and an end");
+            }
+            Ok(nums)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if ranges.len() != 2 {
+        bail!("each line must have two ranges");
+    }
+    Ok(ranges)
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Parsed = Pairs;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Pairs> {
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, line)| parse_pair(line).line_context(index, line))
+            .collect()
+    }
+
+    fn part_a(&self, pairs: &Pairs) -> Result<Answer> {
+        Ok(pairs
+            .iter()
+            .filter(|ranges| superset(ranges))
+            .count()
+            .into())
+    }
+
+    fn part_b(&self, pairs: &Pairs) -> Result<Answer> {
+        Ok(pairs
+            .iter()
+            .filter(|ranges| intersect(ranges))
+            .count()
+            .into())
+    }
+}
@@ -0,0 +1,221 @@
+use anyhow::{bail, Context, Result};
+use common::viz::Scene;
+use common::{blocks, Answer, Block, LineContext, ParseError, Solver, Variant};
+use std::collections::VecDeque;
+
+type Column = VecDeque<char>;
+type Columns = Vec<Column>;
+type Moves = Vec<Move>;
+
+// Specifies |num| moves from |src| to |dest|.
+pub struct Move {
+    num: usize,
+    src: usize,
+    dest: usize,
+}
+
+// Parses the crate line at |index| in the input.
+fn parse_crate_line(cols: &mut Columns, index: usize, line: &str) -> Result<()> {
+    for (i, ch) in line.chars().enumerate() {
+        match ch {
+            '[' | ']' | ' ' => continue,
+            _ => {
+                // Each column in the input is 4 characters wide.
+                let col = i / 4;
+                if !ch.is_alphabetic() {
+                    bail!(ParseError::new("parsing: invalid crate identifier", index, line).at(i))
+                }
+                if cols.len() <= col {
+                    cols.append(&mut vec![Column::new(); col + 1 - cols.len()]);
+                }
+                cols[col].push_front(ch);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn init_columns(block: &Block<String>) -> Result<Columns> {
+    let mut cols = Columns::new();
+
+    // Skip the line enumerating the crates, as this doesn't really help us.
+    for (index, line) in block.numbered().rev().skip(1) {
+        parse_crate_line(&mut cols, index, line)?;
+    }
+    Ok(cols)
+}
+
+fn parse_move_line(line: &str) -> Result<Move> {
+    let parsed = line
+        .split_whitespace()
+        .filter_map(|word| word.parse::<usize>().ok())
+        .collect::<Vec<_>>();
+    if parsed.len() != 3 {
+        bail!("parsing: move line formatted incorrectly");
+    }
+
+    // Note that the inputs are 1-indexed (to reflect the input), but the |Columns| storage
+    // array is 0-indexed, so a decrement needs to happen to adjust.
+    Ok(Move {
+        num: parsed[0],
+        src: parsed[1]
+            .checked_sub(1)
+            .context("parsing: stacks are numbered from 1")?,
+        dest: parsed[2]
+            .checked_sub(1)
+            .context("parsing: stacks are numbered from 1")?,
+    })
+}
+
+fn init_moves(block: &Block<String>) -> Result<Moves> {
+    let mut moves = Moves::new();
+    for (index, line) in block.numbered() {
+        moves.push(parse_move_line(line).line_context(index, line)?)
+    }
+    Ok(moves)
+}
+
+fn apply_move(variant: &Variant, cols: &mut Columns, mv: &Move) -> Result<()> {
+    let num_cols = cols.len();
+    if mv.src >= num_cols {
+        bail!("Tried to move from an unknown stack");
+    }
+    if mv.dest >= num_cols {
+        bail!("Tried to move to an unknown stack");
+    }
+    if mv.num > cols[mv.src].len() {
+        bail!("Tried to move more crates than the stack holds");
+    }
+
+    match variant {
+        Variant::A => {
+            for _ in 0..mv.num {
+                match cols[mv.src].pop_front() {
+                    Some(ch) => cols[mv.dest].push_front(ch),
+                    None => bail!("Tried to move from an empty stack"),
+                }
+            }
+        }
+        Variant::B => {
+            let removed = cols[mv.src].drain(0..mv.num).rev().collect::<Vec<_>>();
+            removed
+                .into_iter()
+                .for_each(|ch| cols[mv.dest].push_front(ch));
+        }
+    }
+    Ok(())
+}
+
+fn apply_moves(variant: &Variant, mut cols: Columns, moves: &[Move]) -> Result<Columns> {
+    for mv in moves {
+        apply_move(variant, &mut cols, mv)?;
+    }
+    Ok(cols)
+}
+
+fn print_top_crates(cols: &Columns) -> Result<String> {
+    let mut out = String::new();
+    for c in cols {
+        match c.front() {
+            Some(ch) => out.push(*ch),
+            None => bail!("cannot print empty stack"),
+        }
+    }
+    Ok(out)
+}
+
+// The crates being restacked by the crane, one move at a time.
+struct Restacking<'a> {
+    variant: Variant,
+    cols: Columns,
+    moves: &'a [Move],
+    done: usize,
+}
+
+impl Scene for Restacking<'_> {
+    fn step(&mut self) -> Result<bool> {
+        let Some(mv) = self.moves.get(self.done) else {
+            return Ok(false);
+        };
+        apply_move(&self.variant, &mut self.cols, mv)?;
+        self.done += 1;
+        Ok(true)
+    }
+
+    // Draw the stacks the way the input does, above the move that was just made.
+    fn frame(&self) -> String {
+        let height = self.cols.iter().map(Column::len).max().unwrap_or(0);
+        let mut rows = Vec::new();
+        for level in (0..height).rev() {
+            let row = self
+                .cols
+                .iter()
+                .map(|col| match col.len() > level {
+                    true => format!("[{}]", col[col.len() - 1 - level]),
+                    false => "   ".to_string(),
+                })
+                .collect::<Vec<_>>();
+            rows.push(row.join(" "));
+        }
+        let numbers = (1..=self.cols.len())
+            .map(|num| format!(" {} ", num))
+            .collect::<Vec<_>>();
+        rows.push(numbers.join(" "));
+        match self.done.checked_sub(1).map(|last| &self.moves[last]) {
+            Some(mv) => rows.push(format!(
+                "\nmove {} from {} to {} ({} of {})",
+                mv.num,
+                mv.src + 1,
+                mv.dest + 1,
+                self.done,
+                self.moves.len()
+            )),
+            None => rows.push(format!("\n{} moves to make", self.moves.len())),
+        }
+        rows.join("\n")
+    }
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Parsed = (Columns, Moves);
+
+    fn parse(&self, lines: Vec<String>) -> Result<(Columns, Moves)> {
+        // The crates are drawn in the first block, and the moves listed in the second.
+        let mut blocks = blocks(lines);
+        let cols = blocks.next().context("parsing: no crates drawn")?;
+        let moves = blocks.next().context("parsing: no moves listed")?;
+        if let Some(extra) = blocks.next() {
+            bail!(ParseError::new(
+                "parsing: unexpected block after the moves",
+                extra.start,
+                &extra.lines[0]
+            ));
+        }
+        Ok((init_columns(&cols)?, init_moves(&moves)?))
+    }
+
+    fn part_a(&self, (cols, moves): &(Columns, Moves)) -> Result<Answer> {
+        let restacked = apply_moves(&Variant::A, cols.clone(), moves)?;
+        Ok(print_top_crates(&restacked)?.into())
+    }
+
+    fn part_b(&self, (cols, moves): &(Columns, Moves)) -> Result<Answer> {
+        let restacked = apply_moves(&Variant::B, cols.clone(), moves)?;
+        Ok(print_top_crates(&restacked)?.into())
+    }
+
+    fn scene<'a>(
+        &self,
+        (cols, moves): &'a (Columns, Moves),
+        variant: Variant,
+    ) -> Result<Box<dyn Scene + 'a>> {
+        Ok(Box::new(Restacking {
+            variant,
+            cols: cols.clone(),
+            moves,
+            done: 0,
+        }))
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::{bail, Context, Result};
+use common::{Answer, Normalize, Solver};
+use std::collections::HashSet;
+
+const MARKER_SIZE_A: usize = 4;
+const MARKER_SIZE_B: usize = 14;
+
+// Find the number of characters read by the end of the first run of |marker_size| distinct ones.
+fn find_marker(stream: &[char], marker_size: usize) -> Result<usize> {
+    stream
+        .windows(marker_size)
+        .position(|window| {
+            let mut seen = HashSet::<char>::new();
+            window.iter().all(|ch| seen.insert(*ch))
+        })
+        .map(|index| index + marker_size)
+        .context("no start-of-packet marker found")
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Parsed = Vec<char>;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Vec<char>> {
+        if lines.len() != 1 {
+            bail!("input must be single line");
+        }
+        Ok(lines[0].chars().collect())
+    }
+
+    fn part_a(&self, stream: &Vec<char>) -> Result<Answer> {
+        Ok(find_marker(stream, MARKER_SIZE_A)?.into())
+    }
+
+    fn part_b(&self, stream: &Vec<char>) -> Result<Answer> {
+        Ok(find_marker(stream, MARKER_SIZE_B)?.into())
+    }
+
+    // A stray blank line after the stream would otherwise make it more than one line.
+    fn normalize(&self) -> Option<Normalize> {
+        Some(Normalize {
+            trim_trailing_whitespace: true,
+        })
+    }
+}
@@ -0,0 +1,201 @@
+use anyhow::{bail, Context, Result};
+use common::{Answer, Normalize, Solver};
+use std::collections::{BTreeSet, HashMap};
+
+const LIMIT: usize = 100_000;
+const DISK_SIZE: usize = 70_000_000;
+const UPDATE_SIZE: usize = 30_000_000;
+
+trait Sizeable {
+    fn get_size(&mut self) -> Result<usize>;
+}
+
+#[derive(Clone, Debug)]
+struct File {
+    size: usize,
+}
+
+impl File {
+    fn new(size: usize) -> File {
+        File { size }
+    }
+}
+
+impl Sizeable for File {
+    fn get_size(&mut self) -> Result<usize> {
+        if self.size == 0 {
+            bail!("tried to get size of file with unknown size")
+        }
+        Ok(self.size)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Dir {
+    // A size of 0 means we do not know the size yet, and must calculate it.
+    size: usize,
+    dirs: HashMap<String, Dir>,
+    files: HashMap<String, File>,
+}
+
+impl Dir {
+    fn new() -> Dir {
+        Dir {
+            size: 0,
+            dirs: HashMap::new(),
+            files: HashMap::new(),
+        }
+    }
+
+    fn add_dir(&mut self, name: &str, dir: Dir) -> Result<&mut Dir> {
+        if self.dirs.insert(name.to_string(), dir).is_none() {
+            self.size = 0
+        }
+        self.get_dir(name).context("could not get just-added dir")
+    }
+
+    fn add_file(&mut self, name: &str, file: File) -> Result<&mut File> {
+        if self.files.insert(name.to_string(), file).is_none() {
+            self.size = 0
+        }
+        self.get_file(name).context("could not get just-added file")
+    }
+
+    fn get_dir(&mut self, name: &str) -> Option<&mut Dir> {
+        self.dirs.get_mut(name)
+    }
+
+    fn get_file(&mut self, name: &str) -> Option<&mut File> {
+        self.files.get_mut(name)
+    }
+
+    // No need for a `visit_files` method, though in theory we could have one.
+    fn visit_dirs<F>(&mut self, f: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Dir) -> Result<()>,
+    {
+        for dir in self.dirs.values_mut() {
+            dir.visit_dirs(f)?;
+        }
+        f(self)
+    }
+}
+
+impl Sizeable for Dir {
+    fn get_size(&mut self) -> Result<usize> {
+        if self.size == 0 {
+            for file in self.files.values_mut() {
+                self.size = self
+                    .size
+                    .checked_add(file.get_size()?)
+                    .context("files too large to add up")?;
+            }
+            for dir in self.dirs.values_mut() {
+                self.size = self
+                    .size
+                    .checked_add(dir.get_size()?)
+                    .context("dirs too large to add up")?;
+            }
+        }
+        Ok(self.size)
+    }
+}
+
+type LinesParsed = usize;
+fn parse(lines: &[String], dir: &mut Dir) -> Result<LinesParsed> {
+    let line_count = lines.len();
+    let mut lines_parsed = 0;
+    while lines_parsed + 1 < line_count {
+        lines_parsed += 1;
+        let mut parts = lines[lines_parsed].split_whitespace();
+        match parts.next() {
+            None => bail!("invalid empty line"),
+            Some(first) => match first {
+                "$" => match parts.next() {
+                    None => bail!("empty command"),
+                    Some(second) => match second {
+                        "ls" => {}
+                        "cd" => match parts.next() {
+                            None => bail!("no target dir for cd command"),
+                            Some(third) => match third {
+                                "/" => bail!("cannot return to root after start"),
+                                ".." => return Ok(lines_parsed),
+                                _ => {
+                                    let next_dir = dir.add_dir(third, Dir::new())?;
+                                    lines_parsed += parse(&lines[lines_parsed..], next_dir)?;
+                                }
+                            },
+                        },
+                        _ => bail!("invalid command"),
+                    },
+                },
+                "dir" => match parts.next() {
+                    None => bail!("invalid dir line in response to ls"),
+                    Some(second) => {
+                        dir.add_dir(second, Dir::new())?;
+                    }
+                },
+                _ => match parts.next() {
+                    None => bail!("invalid dir line in response to ls"),
+                    Some(second) => {
+                        dir.add_file(second, File::new(first.parse::<usize>()?))?;
+                    }
+                },
+            },
+        };
+    }
+    Ok(lines_parsed)
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Parsed = Dir;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Dir> {
+        if lines.is_empty() || lines[0] != "$ cd /" {
+            bail!("first command must be `$ cd /`")
+        }
+
+        let mut root = Dir::new();
+        parse(&lines[1..], &mut root)?;
+        Ok(root)
+    }
+
+    fn part_a(&self, root: &Dir) -> Result<Answer> {
+        let mut root = root.clone();
+        let mut sum: usize = 0;
+        root.visit_dirs(&mut |dir: &mut Dir| {
+            let size = dir.get_size()?;
+            if size <= LIMIT {
+                sum = sum.checked_add(size).context("dirs too large to add up")?;
+            }
+            Ok(())
+        })?;
+        Ok(sum.into())
+    }
+
+    fn part_b(&self, root: &Dir) -> Result<Answer> {
+        let mut root = root.clone();
+        let free_disk_space = DISK_SIZE.checked_sub(root.get_size()?).context("a")?;
+        let deletion_target = UPDATE_SIZE.checked_sub(free_disk_space).context("b")?;
+
+        let mut dir_sizes = BTreeSet::<usize>::new();
+        root.visit_dirs(&mut |dir: &mut Dir| {
+            dir_sizes.insert(dir.get_size()?);
+            Ok(())
+        })?;
+        Ok((*dir_sizes
+            .range(deletion_target..)
+            .next()
+            .context("no directory is small enough")?)
+        .into())
+    }
+
+    // Blank lines at the end would otherwise be read as empty commands.
+    fn normalize(&self) -> Option<Normalize> {
+        Some(Normalize {
+            trim_trailing_whitespace: true,
+        })
+    }
+}
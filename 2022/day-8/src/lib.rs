@@ -0,0 +1,203 @@
+use anyhow::{Context, Result};
+use common::grid::Grid;
+use common::image::{blend, Image, Rgb};
+use common::{Answer, Solver, Variant};
+use std::collections::BTreeMap;
+
+fn count_forwards(grid: &mut Grid<isize>) -> Result<usize> {
+    let height = grid.height();
+    let width = grid.width();
+    let mut rows = vec![0; height];
+    let mut cols = vec![0; width];
+    let mut count = 0;
+    for pos in grid.positions() {
+        let (y, x) = (pos.row, pos.col);
+        let cell = grid[pos];
+        let abs = cell.abs();
+        let mut inc = false;
+        if abs >= rows[y] {
+            rows[y] = abs + 1;
+            inc = true;
+        }
+        if abs >= cols[x] {
+            cols[x] = abs + 1;
+            inc = true;
+        }
+        if inc && cell >= 0 {
+            grid[pos] = -cell;
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+fn count_backwards(grid: &mut Grid<isize>) -> Result<usize> {
+    let height = grid.height();
+    let width = grid.width();
+    let mut rows = vec![0; height];
+    let mut cols = vec![0; width];
+    let mut count = 0;
+    for pos in grid.positions().rev() {
+        let (y, x) = (pos.row, pos.col);
+        let cell = grid[pos];
+        let abs = cell.abs();
+        let mut inc = false;
+        if abs >= rows[y] {
+            rows[y] = abs + 1;
+            inc = true;
+        }
+        if abs >= cols[x] {
+            cols[x] = abs + 1;
+            inc = true;
+        }
+        if inc && cell >= 0 {
+            grid[pos] = -cell;
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+#[derive(Clone, Debug, Default)]
+struct Distances {
+    left: usize,
+    right: usize,
+    up: usize,
+    down: usize,
+}
+
+type DistanceGrid = Grid<Distances>;
+type ViewBlockers = BTreeMap<u8, usize>;
+
+// We can compute the `left` and `up` distances by walking forwards.
+fn score_forwards(grid: &mut Grid<isize>) -> Result<DistanceGrid> {
+    let height = grid.height();
+    let width = grid.width();
+    let mut distances = DistanceGrid::new(width, height, Distances::default());
+    let mut rows = vec![ViewBlockers::new(); height];
+    let mut cols = vec![ViewBlockers::new(); width];
+    for pos in grid.positions() {
+        let (y, x) = (pos.row, pos.col);
+        let cell = grid[pos] as u8;
+        let row_blockers = &mut rows[y];
+        let col_blockers = &mut cols[x];
+
+        // Score looking left.
+        *row_blockers = row_blockers.split_off(&cell);
+        distances[pos].left = match row_blockers.range(cell..).next() {
+            Some((_, index)) => x - index,
+            None => x,
+        };
+        row_blockers.insert(cell, x);
+
+        // Score looking up.
+        *col_blockers = col_blockers.split_off(&cell);
+        distances[pos].up = match col_blockers.range(cell..).next() {
+            Some((_, index)) => y - index,
+            None => y,
+        };
+        col_blockers.insert(cell, y);
+    }
+    Ok(distances)
+}
+
+// We can compute the `right` and `down` distances by walking forwards.
+fn score_backwards(grid: &mut Grid<isize>, mut distances: DistanceGrid) -> Result<usize> {
+    let height = grid.height();
+    let width = grid.width();
+    let mut rows = vec![ViewBlockers::new(); height];
+    let mut cols = vec![ViewBlockers::new(); width];
+    let mut max = 0;
+    for pos in grid.positions().rev() {
+        let (y, x) = (pos.row, pos.col);
+        let cell = grid[pos] as u8;
+        let dist = &mut distances[pos];
+        let row_blockers = &mut rows[y];
+        let col_blockers = &mut cols[x];
+
+        // Score looking right.
+        *row_blockers = row_blockers.split_off(&cell);
+        dist.right = match row_blockers.range(cell..).next() {
+            Some((_, index)) => index - x,
+            None => (width - 1) - x,
+        };
+        row_blockers.insert(cell, x);
+
+        // Score looking up.
+        *col_blockers = col_blockers.split_off(&cell);
+        dist.down = match col_blockers.range(cell..).next() {
+            Some((_, index)) => index - y,
+            None => (height - 1) - y,
+        };
+        col_blockers.insert(cell, y);
+
+        // Calculate total score.
+        let score = dist.left * dist.right * dist.up * dist.down;
+        if score > max {
+            max = score;
+        }
+    }
+    Ok(max)
+}
+
+// Marks the trees that can be seen from outside the forest by negating their heights, returning
+// the marked grid and how many were marked. The heights are lifted by one first, as negating the
+// trees of height zero wouldn't mark them.
+fn mark_visible(grid: &Grid<isize>) -> Result<(Grid<isize>, usize)> {
+    let mut grid = grid.map(|height| height + 1);
+    let count = count_forwards(&mut grid)? + count_backwards(&mut grid)?;
+    Ok((grid, count))
+}
+
+fn init_grid(lines: Vec<String>) -> Result<Grid<isize>> {
+    Grid::parse(&lines, |_, ch| {
+        let num = ch.to_digit(10).context("invalid digit")?;
+        Ok(num as isize)
+    })
+}
+
+// How trees are coloured in a picture of the forest, from the shortest to the tallest, and how many
+// pixels wide each of them is.
+const SHORTEST: Rgb = [16, 48, 16];
+const TALLEST: Rgb = [144, 238, 144];
+const VISIBLE: Rgb = [255, 215, 0];
+const TREE_SCALE: usize = 4;
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Parsed = Grid<isize>;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Grid<isize>> {
+        init_grid(lines)
+    }
+
+    fn part_a(&self, grid: &Grid<isize>) -> Result<Answer> {
+        let (_, count) = mark_visible(grid)?;
+        Ok(count.into())
+    }
+
+    fn part_b(&self, grid: &Grid<isize>) -> Result<Answer> {
+        let mut grid = grid.clone();
+        let distances = score_forwards(&mut grid)?;
+        Ok(score_backwards(&mut grid, distances)?.into())
+    }
+
+    // The tree heights, with the trees that can be seen from outside the forest picked out in
+    // part a.
+    fn picture(&self, grid: &Grid<isize>, variant: Variant) -> Result<Image> {
+        // Heights are lifted by one either way, so that they're shaded the same in both parts.
+        let grid = match variant {
+            Variant::A => mark_visible(grid)?.0,
+            Variant::B => grid.map(|height| height + 1),
+        };
+        let image = Image::from_grid(&grid, |_, &height| {
+            let shade = blend(SHORTEST, TALLEST, height.unsigned_abs() - 1, 9);
+            match height < 0 {
+                true => blend(shade, VISIBLE, 1, 2),
+                false => shade,
+            }
+        });
+        Ok(image.scaled(TREE_SCALE))
+    }
+}
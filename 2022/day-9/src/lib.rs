@@ -0,0 +1,215 @@
+use anyhow::{bail, Context, Result};
+use common::viz::Scene;
+use common::{Answer, LineContext, Solver, Unsolved, Variant};
+use std::collections::HashSet;
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+pub struct Movement {
+    direction: Direction,
+    magnitude: i16,
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+struct Position {
+    x: i16,
+    y: i16,
+}
+
+#[derive(Default)]
+struct State {
+    head: Position,
+    tail: Position,
+}
+
+fn move_head<'a>(head: &'a mut Position, movement: &Movement) -> Result<&'a mut Position> {
+    let moved = match movement.direction {
+        Direction::Up => head.y.checked_add(movement.magnitude),
+        Direction::Down => head.y.checked_sub(movement.magnitude),
+        Direction::Right => head.x.checked_add(movement.magnitude),
+        Direction::Left => head.x.checked_sub(movement.magnitude),
+    }
+    .context("the head moved out of range")?;
+    match movement.direction {
+        Direction::Up | Direction::Down => head.y = moved,
+        Direction::Right | Direction::Left => head.x = moved,
+    };
+    Ok(head)
+}
+
+fn move_tail(head: &mut Position, tail: &mut Position, visited: &mut HashSet<Position>) {
+    loop {
+        // The head can be further from the tail than a |Position| can hold.
+        let x_diff = i32::from(head.x) - i32::from(tail.x);
+        let y_diff = i32::from(head.y) - i32::from(tail.y);
+        if x_diff.abs() <= 1 && y_diff.abs() <= 1 {
+            // The tail has moved to its final position.
+            return;
+        }
+        if x_diff != 0 {
+            // The tail needs to move horizontally.
+            if x_diff > 0 {
+                tail.x += 1;
+            } else {
+                tail.x -= 1;
+            }
+        }
+        if y_diff != 0 {
+            // The tail needs to move vertically.
+            if y_diff > 0 {
+                tail.y += 1;
+            } else {
+                tail.y -= 1;
+            }
+        }
+        visited.insert(tail.clone());
+    }
+}
+
+fn parse_line(line: &str) -> Result<Movement> {
+    let mut parts = line.split_whitespace();
+    let out = Movement {
+        direction: match parts.next().context("parsing: missing direction")? {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            _ => bail!("parsing: unknown direction"),
+        },
+        magnitude: parts
+            .next()
+            .context("parsing: missing direction")?
+            .parse()?,
+    };
+    if parts.next().is_some() {
+        bail!("parsing: too many parts");
+    }
+    Ok(out)
+}
+
+// The rope being pulled around one knot-width at a time, so that the tail can be seen following.
+struct Pulling<'a> {
+    movements: &'a [Movement],
+    next: usize,
+    // The head's movement still to be made, a step at a time.
+    left: u16,
+    step: Movement,
+    state: State,
+    visited: HashSet<Position>,
+}
+
+// How much of the rope's surroundings are drawn, centred on the head.
+const VIEW_WIDTH: i16 = 60;
+const VIEW_HEIGHT: i16 = 20;
+
+impl Scene for Pulling<'_> {
+    fn step(&mut self) -> Result<bool> {
+        while self.left == 0 {
+            let Some(movement) = self.movements.get(self.next) else {
+                return Ok(false);
+            };
+            self.next += 1;
+            self.left = movement.magnitude.unsigned_abs();
+            self.step = Movement {
+                direction: movement.direction,
+                magnitude: movement.magnitude.signum(),
+            };
+        }
+        move_tail(
+            move_head(&mut self.state.head, &self.step)?,
+            &mut self.state.tail,
+            &mut self.visited,
+        );
+        self.left -= 1;
+        Ok(true)
+    }
+
+    fn frame(&self) -> String {
+        let head = &self.state.head;
+        let mut rows = Vec::new();
+        // Up is towards larger y, so the top row is drawn first.
+        for y in (head.y - VIEW_HEIGHT / 2..head.y + VIEW_HEIGHT / 2).rev() {
+            let row = (head.x - VIEW_WIDTH / 2..head.x + VIEW_WIDTH / 2)
+                .map(|x| {
+                    let pos = Position { x, y };
+                    match pos {
+                        _ if pos == self.state.head => 'H',
+                        _ if pos == self.state.tail => 'T',
+                        Position { x: 0, y: 0 } => 's',
+                        _ if self.visited.contains(&pos) => '#',
+                        _ => '.',
+                    }
+                })
+                .collect::<String>();
+            rows.push(row);
+        }
+        rows.push(format!(
+            "\nmove {} of {}, tail visited {} positions",
+            self.next,
+            self.movements.len(),
+            self.visited.len()
+        ));
+        rows.join("\n")
+    }
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    type Parsed = Vec<Movement>;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Vec<Movement>> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| parse_line(line.as_str()).line_context(index, line))
+            .collect()
+    }
+
+    fn part_a(&self, movements: &Vec<Movement>) -> Result<Answer> {
+        let mut state = State::default();
+        let mut visited = HashSet::<Position>::new();
+        visited.insert(state.tail.clone());
+        for movement in movements {
+            move_tail(
+                move_head(&mut state.head, movement)?,
+                &mut state.tail,
+                &mut visited,
+            );
+        }
+        Ok(visited.len().into())
+    }
+
+    fn part_b(&self, _movements: &Vec<Movement>) -> Result<Answer> {
+        bail!(Unsolved(Variant::B))
+    }
+
+    fn scene<'a>(
+        &self,
+        movements: &'a Vec<Movement>,
+        variant: Variant,
+    ) -> Result<Box<dyn Scene + 'a>> {
+        if variant == Variant::B {
+            bail!(Unsolved(Variant::B));
+        }
+        let state = State::default();
+        let visited = HashSet::from([state.tail.clone()]);
+        Ok(Box::new(Pulling {
+            movements,
+            next: 0,
+            left: 0,
+            step: Movement {
+                direction: Direction::Up,
+                magnitude: 0,
+            },
+            state,
+            visited,
+        }))
+    }
+}
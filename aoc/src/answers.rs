@@ -0,0 +1,82 @@
+use anyhow::{Context, Result};
+use common::{Answer, Variant};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// The name of the file, kept alongside each day's `input.txt`, that records accepted answers.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// An accepted answer, as written in an `answers.toml`. Multi-line renders are written as strings.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum Expected {
+    Int(i64),
+    Str(String),
+}
+
+impl Expected {
+    /// Whether the |answer| produced by a solver is the one that was accepted.
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match (self, answer) {
+            (Expected::Int(expected), Answer::Int(actual)) => expected == actual,
+            (Expected::Str(expected), Answer::Str(actual)) => expected == actual,
+            (Expected::Str(expected), Answer::Render(rows)) => {
+                expected.trim_end_matches('\n').lines().eq(rows.iter())
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The accepted answers for one input file. A variant that hasn't been solved is left out.
+#[derive(Debug, Default, Deserialize)]
+pub struct Parts {
+    a: Option<Expected>,
+    b: Option<Expected>,
+}
+
+impl Parts {
+    pub fn get(&self, variant: Variant) -> Option<&Expected> {
+        match variant {
+            Variant::A => self.a.as_ref(),
+            Variant::B => self.b.as_ref(),
+        }
+    }
+}
+
+/// Every accepted answer for a day, keyed by the stem of the input file they are for (so the
+/// answers for `input.txt` live under `[input]`).
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    inputs: BTreeMap<String, Parts>,
+}
+
+impl Answers {
+    /// Load the `answers.toml` from a day's directory.
+    pub fn load(day_dir: &Path) -> Result<Answers> {
+        let path = day_dir.join(ANSWERS_FILE);
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("could not parse {}", path.display()))
+    }
+
+    /// Load the `answers.toml` from a day's directory, if the day has one.
+    pub fn load_if_present(day_dir: &Path) -> Result<Option<Answers>> {
+        match day_dir.join(ANSWERS_FILE).exists() {
+            true => Ok(Some(Answers::load(day_dir)?)),
+            false => Ok(None),
+        }
+    }
+
+    /// The stem of every input file that has answers recorded.
+    pub fn inputs(&self) -> impl Iterator<Item = &str> {
+        self.inputs.keys().map(String::as_str)
+    }
+
+    pub fn get(&self, input: &str, variant: Variant) -> Option<&Expected> {
+        self.inputs.get(input)?.get(variant)
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::{Error, Result};
+use common::{read_input_file_lines, DynSolver, Variant};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// The spread of the times taken by one phase over every run.
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        Stats {
+            median: samples[samples.len() / 2],
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// How long each phase of solving a day took. A variant that fails is not timed any further, and
+/// keeps the error it failed with instead.
+pub struct Timings {
+    pub read: Stats,
+    pub parse: Stats,
+    pub parts: Vec<(Variant, Result<Stats>)>,
+}
+
+fn time<T>(samples: &mut Vec<Duration>, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let start = Instant::now();
+    let out = black_box(f()?);
+    samples.push(start.elapsed());
+    Ok(out)
+}
+
+/// Read, parse and solve each of the |variants| from the |input| file |runs| times over, timing
+/// each of those phases separately.
+pub fn bench(
+    solver: &dyn DynSolver,
+    input: &Path,
+    variants: &[Variant],
+    runs: usize,
+) -> Result<Timings> {
+    let mut read = Vec::with_capacity(runs);
+    let mut parse = Vec::with_capacity(runs);
+    let mut parts = variants
+        .iter()
+        .map(|variant| (*variant, Ok::<_, Error>(Vec::with_capacity(runs))))
+        .collect::<Vec<_>>();
+    for _ in 0..runs.max(1) {
+        let lines = time(&mut read, || read_input_file_lines(input))?;
+        let parsed = time(&mut parse, || solver.parse_input(lines))?;
+        for (variant, samples) in parts.iter_mut() {
+            if let Ok(timed) = samples {
+                if let Err(err) = time(timed, || solver.solve_parsed(parsed.as_ref(), *variant)) {
+                    *samples = Err(err);
+                }
+            }
+        }
+    }
+
+    Ok(Timings {
+        read: Stats::from_samples(read),
+        parse: Stats::from_samples(parse),
+        parts: parts
+            .into_iter()
+            .map(|(variant, samples)| (variant, samples.map(Stats::from_samples)))
+            .collect(),
+    })
+}
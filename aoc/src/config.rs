@@ -0,0 +1,47 @@
+use crate::workspace_root;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The name of the per-user config file, kept at the root of the workspace and out of git.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// The environment variable that can point at a config file somewhere else.
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+/// Where puzzle inputs are fetched from, unless the config says otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the puzzle server, read from an `aoc.toml` like:
+///
+/// ```toml
+/// session = "<the value of the session cookie>"
+/// base_url = "https://adventofcode.com"  # optional
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+pub struct Config {
+    pub session: String,
+    #[serde(default = "default_base_url")]
+    pub base_url: String,
+}
+
+fn default_base_url() -> String {
+    DEFAULT_BASE_URL.to_string()
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Config> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("could not read config file {}", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("could not parse {}", path.display()))
+    }
+
+    /// The config file named by `AOC_CONFIG`, or else the `aoc.toml` at the workspace root.
+    pub fn default_path() -> PathBuf {
+        match std::env::var_os(CONFIG_ENV) {
+            Some(path) => PathBuf::from(path),
+            None => workspace_root().join(CONFIG_FILE),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use common::Puzzle;
+use std::fs;
+use std::path::Path;
+
+/// Identifies this tool to the puzzle server, as its maintainers ask automated clients to.
+pub const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+/// Whether |input| already holds a downloaded input. An empty file is a placeholder, not a cache.
+pub fn is_cached(input: &Path) -> bool {
+    fs::metadata(input).is_ok_and(|meta| meta.len() > 0)
+}
+
+/// Download the input for |puzzle| from the configured server and cache it at |input|. Refuses to
+/// replace an input that has already been cached, so that the server is only asked once.
+pub fn fetch(config: &Config, puzzle: Puzzle, input: &Path) -> Result<()> {
+    if is_cached(input) {
+        bail!(
+            "the input for day {} is already cached at {}",
+            puzzle.day,
+            input.display()
+        );
+    }
+
+    let url = format!(
+        "{}/{}/day/{}/input",
+        config.base_url.trim_end_matches('/'),
+        puzzle.year,
+        puzzle.day
+    );
+    let body = ureq::get(&url)
+        .set("Cookie", &format!("session={}", config.session))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .with_context(|| format!("could not fetch {}", url))?
+        .into_string()
+        .with_context(|| format!("could not read the response from {}", url))?;
+    if body.is_empty() {
+        bail!("{} returned an empty input", url);
+    }
+
+    if let Some(dir) = input.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("could not create {}", dir.display()))?;
+    }
+    fs::write(input, body).with_context(|| format!("could not write {}", input.display()))
+}
@@ -0,0 +1,95 @@
+use common::{DynSolver, Puzzle};
+use std::path::PathBuf;
+
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod fetch;
+pub mod parallel;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+
+/// The environment variable that limits this crate's answer tests to a single year, as set by
+/// `aoc test --year <year>`.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// The days of one year that have a solver, in the order they should be run.
+pub type Days = &'static [(u8, &'static dyn DynSolver)];
+
+/// Every day of 2022 with a solver.
+const DAYS_2022: Days = &[
+    (1, &y2022_day_1::Day1),
+    (2, &y2022_day_2::Day2),
+    (3, &y2022_day_3::Day3),
+    (4, &y2022_day_4::Day4),
+    (5, &y2022_day_5::Day5),
+    (6, &y2022_day_6::Day6),
+    (7, &y2022_day_7::Day7),
+    (8, &y2022_day_8::Day8),
+    (9, &y2022_day_9::Day9),
+    (10, &y2022_day_10::Day10),
+    (11, &y2022_day_11::Day11),
+    (12, &y2022_day_12::Day12),
+    (13, &y2022_day_13::Day13),
+    (14, &y2022_day_14::Day14),
+];
+
+/// Every year with solvers, oldest first.
+pub const YEARS: &[(u16, Days)] = &[(2022, DAYS_2022)];
+
+/// The most recent year with solvers, which is picked when no year is given.
+pub fn latest_year() -> u16 {
+    YEARS.last().map(|(year, _)| *year).unwrap()
+}
+
+/// The days registered for |year|, if any.
+pub fn days(year: u16) -> Option<Days> {
+    YEARS
+        .iter()
+        .find(|(registered, _)| *registered == year)
+        .map(|(_, days)| *days)
+}
+
+/// Every registered day, across every year, along with its solver.
+pub fn all_days() -> impl Iterator<Item = (Puzzle, &'static dyn DynSolver)> {
+    YEARS.iter().flat_map(|(year, days)| {
+        days.iter().map(move |(day, solver)| {
+            (
+                Puzzle {
+                    year: *year,
+                    day: *day,
+                },
+                *solver,
+            )
+        })
+    })
+}
+
+/// Look up the solver registered for |puzzle|, if any.
+pub fn find(puzzle: Puzzle) -> Option<&'static dyn DynSolver> {
+    days(puzzle.year)?
+        .iter()
+        .find(|(registered, _)| *registered == puzzle.day)
+        .map(|(_, solver)| *solver)
+}
+
+/// The root of this workspace, which holds a directory for each year.
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// The directory of a year's puzzles, which holds the crate for each of its days.
+pub fn year_dir(year: u16) -> PathBuf {
+    workspace_root().join(year.to_string())
+}
+
+/// The directory of each day's crate in this workspace, which holds its `input.txt`.
+pub fn day_dir(puzzle: Puzzle) -> PathBuf {
+    year_dir(puzzle.year).join(format!("day-{}", puzzle.day))
+}
+
+/// The `input.txt` that lives alongside each day's crate in this workspace.
+pub fn default_input_path(puzzle: Puzzle) -> PathBuf {
+    day_dir(puzzle).join("input.txt")
+}
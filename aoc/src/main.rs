@@ -0,0 +1,510 @@
+use anyhow::{bail, Context, Result};
+use aoc::bench::{bench, Stats};
+use aoc::config::Config;
+use aoc::fetch::fetch;
+use aoc::parallel::{solve_all, summary_table, tally, Job};
+use aoc::scaffold::new_day;
+use aoc::submit::{submit, History, Response, Verdict};
+use aoc::watch::watch;
+use aoc::{
+    all_days, day_dir, days, default_input_path, find, latest_year, workspace_root, YEAR_ENV,
+};
+use common::{
+    json_line, Answer, DynSolver, Format, Input, Puzzle, Selection, Solved, Unsolved, Variant,
+};
+use std::path::PathBuf;
+use std::process;
+use std::thread;
+use std::time::Instant;
+
+const USAGE: &str = "usage:
+    aoc run [--year <year>] (--day <N> | --all) [--part <a|b|both>] [--input <path>]
+        [--format <text|json>] [--jobs <N>]
+    aoc bench [--year <year>] (--day <N> | --all) [--part <a|b|both>] [--input <path>]
+        [--runs <N>]
+    aoc fetch [--year <year>] --day <N> [--config <path>]
+    aoc new [--year <year>] --day <N>
+    aoc submit [--year <year>] --day <N> --part <a|b> [--config <path>]
+    aoc test [--year <year>] [-- <cargo test arguments>]
+    aoc watch [--year <year>] --day <N>
+the year defaults to the latest one, except that --all without a year covers every year; --all
+solves the days on --jobs threads (one per core by default) and checks them against answers.toml;
+watch rebuilds the day and checks its examples and input whenever its files change; an --input of
+`-` reads from stdin, and the config defaults to $AOC_CONFIG or aoc.toml";
+
+/// How many times `aoc bench` solves each day, unless told otherwise.
+const DEFAULT_RUNS: usize = 10;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Command {
+    Run,
+    Bench,
+}
+
+/// Which of the registered days an invocation should solve.
+enum Days {
+    One(u8),
+    All,
+}
+
+struct RunArgs {
+    /// Limits the days to one year; the latest year for a single day, or every year for `--all`.
+    year: Option<u16>,
+    days: Days,
+
+    /// Which variants to solve; both of them if unset.
+    selection: Option<Selection>,
+
+    /// Overrides the day's own `input.txt`; only allowed when solving a single day.
+    input: Option<Input>,
+
+    /// How many times to repeat each day when benchmarking.
+    runs: usize,
+
+    /// How to print answers when running.
+    format: Format,
+
+    /// How many threads to solve days on when running `--all`.
+    jobs: usize,
+}
+
+fn parse_run_args(command: Command, mut args: impl Iterator<Item = String>) -> Result<RunArgs> {
+    let mut year = None;
+    let mut day = None;
+    let mut all = false;
+    let mut selection = None;
+    let mut input = None;
+    let mut runs = DEFAULT_RUNS;
+    let mut format = Format::Text;
+    let mut jobs = thread::available_parallelism().map_or(1, |jobs| jobs.get());
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => {
+                let value = args.next().context("--day requires a value")?;
+                day = Some(value.parse::<u8>().context("invalid day")?);
+            }
+            "--year" => {
+                let value = args.next().context("--year requires a value")?;
+                year = Some(value.parse::<u16>().context("invalid year")?);
+            }
+            "--all" => all = true,
+            "--part" => {
+                let value = args.next().context("--part requires a value")?;
+                selection = Some(Selection::parse(value.as_str())?);
+            }
+            "--input" => {
+                let value = args.next().context("--input requires a value")?;
+                input = Some(Input::from_arg(&value));
+            }
+            "--runs" if command == Command::Bench => {
+                let value = args.next().context("--runs requires a value")?;
+                runs = value.parse::<usize>().context("invalid number of runs")?;
+                if runs == 0 {
+                    bail!("--runs must be at least 1");
+                }
+            }
+            "--format" if command == Command::Run => {
+                let value = args.next().context("--format requires a value")?;
+                format = Format::parse(&value)?;
+            }
+            "--jobs" if command == Command::Run => {
+                let value = args.next().context("--jobs requires a value")?;
+                jobs = value.parse::<usize>().context("invalid number of jobs")?;
+                if jobs == 0 {
+                    bail!("--jobs must be at least 1");
+                }
+            }
+            _ => bail!("unknown argument `{}`\n{}", arg, USAGE),
+        }
+    }
+
+    let days = match (day, all) {
+        (Some(day), false) => Days::One(day),
+        (None, true) => Days::All,
+        _ => bail!("must specify exactly one of --day or --all\n{}", USAGE),
+    };
+    if all && input.is_some() {
+        bail!("--input cannot be combined with --all");
+    }
+    Ok(RunArgs {
+        year,
+        days,
+        selection,
+        input,
+        runs,
+        format,
+        jobs,
+    })
+}
+
+impl RunArgs {
+    fn variants(&self) -> &'static [Variant] {
+        self.selection.unwrap_or(Selection::Both).variants()
+    }
+
+    /// Every day that was asked for, along with its solver.
+    fn puzzles(&self) -> Result<Vec<(Puzzle, &'static dyn DynSolver)>> {
+        match (&self.days, self.year) {
+            (Days::One(day), year) => {
+                let puzzle = Puzzle {
+                    year: year.unwrap_or_else(latest_year),
+                    day: *day,
+                };
+                let solver =
+                    find(puzzle).with_context(|| format!("no solver registered for {}", puzzle))?;
+                Ok(vec![(puzzle, solver)])
+            }
+            (Days::All, Some(year)) => {
+                let days =
+                    days(year).with_context(|| format!("no solvers registered for {}", year))?;
+                Ok(days
+                    .iter()
+                    .map(|(day, solver)| (Puzzle { year, day: *day }, *solver))
+                    .collect())
+            }
+            (Days::All, None) => Ok(all_days().collect()),
+        }
+    }
+
+    /// The input to solve |puzzle| from: the one given, or else the day's own `input.txt`.
+    fn input(&self, puzzle: Puzzle) -> Input {
+        self.input
+            .clone()
+            .unwrap_or_else(|| Input::File(default_input_path(puzzle)))
+    }
+}
+
+/// Solve the variants picked by |args| for one day from a single parse of its |input|, printing
+/// each answer in the requested format. Returns how many of the variants failed, after reporting
+/// why on stderr.
+fn run_day(puzzle: Puzzle, solver: &dyn DynSolver, input: &Input, args: &RunArgs) -> usize {
+    let variants = args.variants();
+    let solved = match input
+        .read_lines()
+        .and_then(|lines| solver.solve_each(lines, variants))
+    {
+        Ok(solved) => solved,
+        Err(err) => {
+            eprintln!("{} failed: {:#}", puzzle, err);
+            return variants.len();
+        }
+    };
+    let mut failures = 0;
+    for Solved {
+        variant,
+        answer,
+        elapsed,
+    } in solved
+    {
+        match answer {
+            Ok(answer) if args.format == Format::Json => {
+                println!("{}", json_line(puzzle, variant, &answer, elapsed))
+            }
+            Ok(answer @ Answer::Render(_)) => println!("{}, part {}:\n{}", puzzle, variant, answer),
+            Ok(answer) => println!("{}, part {}: {}", puzzle, variant, answer),
+            // A part still to be solved is reported, but doesn't fail the run.
+            Err(err) if err.is::<Unsolved>() => eprintln!("{}, part {}: {}", puzzle, variant, err),
+            Err(err) => {
+                eprintln!("{} part {} failed: {:#}", puzzle, variant, err);
+                failures += 1;
+            }
+        }
+    }
+    failures
+}
+
+/// Solve every day picked by |args| on a pool of threads, and print a table of the answers checked
+/// against each day's `answers.toml`, or the answers alone as JSON. Returns how many of the variants
+/// failed.
+fn run_all(args: &RunArgs) -> Result<usize> {
+    let jobs = args
+        .puzzles()?
+        .into_iter()
+        .map(|(puzzle, solver)| Job {
+            puzzle,
+            solver,
+            input: args.input(puzzle),
+        })
+        .collect::<Vec<_>>();
+    let start = Instant::now();
+    let outcomes = solve_all(&jobs, args.variants(), args.jobs);
+    let elapsed = start.elapsed();
+
+    if args.format == Format::Json {
+        for outcome in &outcomes {
+            match &outcome.answer {
+                Ok(answer) => println!(
+                    "{}",
+                    json_line(outcome.puzzle, outcome.variant, answer, outcome.elapsed)
+                ),
+                Err(err) => eprintln!(
+                    "{} part {} failed: {:#}",
+                    outcome.puzzle, outcome.variant, err
+                ),
+            }
+        }
+        return Ok(outcomes
+            .iter()
+            .filter(|outcome| outcome.answer.is_err() && !outcome.unsolved())
+            .count());
+    }
+    print!("{}", summary_table(&outcomes));
+    let tally = tally(&outcomes);
+    println!(
+        "\n{} part(s) in {:.2?} on {} thread(s): {} passed, {} failed, {} unsolved, {} unchecked",
+        outcomes.len(),
+        elapsed,
+        args.jobs.min(jobs.len()).max(1),
+        tally.passed,
+        tally.failed,
+        tally.unsolved,
+        tally.unchecked
+    );
+    Ok(tally.failed)
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let failures = match args.days {
+        Days::All => run_all(&args)?,
+        // Keep going past failures, so that one broken day doesn't hide the rest.
+        Days::One(_) => args
+            .puzzles()?
+            .into_iter()
+            .map(|(puzzle, solver)| run_day(puzzle, solver, &args.input(puzzle), &args))
+            .sum(),
+    };
+    if failures > 0 {
+        bail!("{} part(s) failed", failures);
+    }
+    Ok(())
+}
+
+fn print_stats(phase: &str, stats: &Stats) {
+    println!(
+        "  {:<22}{:>12.2?}{:>12.2?}{:>12.2?}",
+        phase, stats.median, stats.min, stats.max
+    );
+}
+
+fn bench_day(puzzle: Puzzle, solver: &dyn DynSolver, args: &RunArgs) -> Result<()> {
+    let input = match args.input(puzzle) {
+        Input::File(path) => path,
+        Input::Stdin => bail!("cannot benchmark stdin, since it can only be read once"),
+    };
+    let timings = bench(solver, &input, args.variants(), args.runs)
+        .with_context(|| format!("{} failed", puzzle))?;
+    println!(
+        "{:<24}{:>12}{:>12}{:>12}",
+        format!("{} ({} runs)", puzzle, args.runs),
+        "median",
+        "min",
+        "max"
+    );
+    print_stats("read", &timings.read);
+    print_stats("parse", &timings.parse);
+    for (variant, stats) in &timings.parts {
+        let phase = format!("part {}", variant);
+        match stats {
+            Ok(stats) => print_stats(&phase, stats),
+            Err(err) => println!("  {:<22}failed: {:#}", phase, err),
+        }
+    }
+    Ok(())
+}
+
+fn run_bench(args: RunArgs) -> Result<()> {
+    args.puzzles()?
+        .into_iter()
+        .try_for_each(|(puzzle, solver)| bench_day(puzzle, solver, &args))
+}
+
+/// Download a day's input into its `input.txt`, using the session token and server from the config.
+fn run_fetch(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut year = latest_year();
+    let mut day = None;
+    let mut config = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => {
+                let value = args.next().context("--year requires a value")?;
+                year = value.parse::<u16>().context("invalid year")?;
+            }
+            "--day" => {
+                let value = args.next().context("--day requires a value")?;
+                day = Some(value.parse::<u8>().context("invalid day")?);
+            }
+            "--config" => {
+                config = Some(PathBuf::from(
+                    args.next().context("--config requires a value")?,
+                ));
+            }
+            _ => bail!("unknown argument `{}`\n{}", arg, USAGE),
+        }
+    }
+    let day = day.with_context(|| format!("must specify --day\n{}", USAGE))?;
+    let config = Config::load(&config.unwrap_or_else(Config::default_path))?;
+
+    let puzzle = Puzzle { year, day };
+    let input = default_input_path(puzzle);
+    fetch(&config, puzzle, &input)?;
+    println!("Fetched {} into {}", puzzle, input.display());
+    Ok(())
+}
+
+/// Solve one part of a day from its own input, and submit the answer unless the day's history of
+/// submissions already rules it out.
+fn run_submit(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut year = latest_year();
+    let mut day = None;
+    let mut variant = None;
+    let mut config = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => {
+                let value = args.next().context("--year requires a value")?;
+                year = value.parse::<u16>().context("invalid year")?;
+            }
+            "--day" => {
+                let value = args.next().context("--day requires a value")?;
+                day = Some(value.parse::<u8>().context("invalid day")?);
+            }
+            "--part" => {
+                let value = args.next().context("--part requires a value")?;
+                variant = Some(Variant::parse(value.as_str())?);
+            }
+            "--config" => {
+                config = Some(PathBuf::from(
+                    args.next().context("--config requires a value")?,
+                ));
+            }
+            _ => bail!("unknown argument `{}`\n{}", arg, USAGE),
+        }
+    }
+    let (Some(day), Some(variant)) = (day, variant) else {
+        bail!("must specify --day and --part\n{}", USAGE);
+    };
+    let config = Config::load(&config.unwrap_or_else(Config::default_path))?;
+
+    let puzzle = Puzzle { year, day };
+    let solver = find(puzzle).with_context(|| format!("no solver registered for {}", puzzle))?;
+    let answer = solver
+        .solve(
+            Input::File(default_input_path(puzzle)).read_lines()?,
+            variant,
+        )
+        .with_context(|| format!("{} part {} failed", puzzle, variant))?;
+    let mut history = History::load(&day_dir(puzzle))?;
+    match submit(&config, puzzle, variant, &answer, &mut history)? {
+        Response::Judged(Verdict::Correct) => {
+            println!("{}, part {}: {} is correct", puzzle, variant, answer);
+            Ok(())
+        }
+        Response::Judged(verdict) => bail!("{} is {}", answer, verdict),
+        Response::RateLimited(Some(wait)) => bail!("rate limited, try again in {}", wait),
+        Response::RateLimited(None) => bail!("rate limited, try again later"),
+        Response::WrongLevel => bail!("part {} is already solved or not unlocked", variant),
+    }
+}
+
+/// Generate a new day's crate and wire it into the workspace and this runner.
+fn run_new(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut year = latest_year();
+    let mut day = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => {
+                let value = args.next().context("--year requires a value")?;
+                year = value.parse::<u16>().context("invalid year")?;
+            }
+            "--day" => {
+                let value = args.next().context("--day requires a value")?;
+                day = Some(value.parse::<u8>().context("invalid day")?);
+            }
+            _ => bail!("unknown argument `{}`\n{}", arg, USAGE),
+        }
+    }
+    let day = day.with_context(|| format!("must specify --day\n{}", USAGE))?;
+
+    let puzzle = Puzzle { year, day };
+    let dir = new_day(&workspace_root(), puzzle)?;
+    println!("Created {} in {}", puzzle, dir.display());
+    Ok(())
+}
+
+/// Run the whole test suite for one year: each of its day crates' own tests, and this runner's
+/// tests limited to that year. Anything after `--` is passed on to `cargo test`.
+fn run_tests(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut year = latest_year();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => {
+                let value = args.next().context("--year requires a value")?;
+                year = value.parse::<u16>().context("invalid year")?;
+            }
+            "--" => break,
+            _ => bail!("unknown argument `{}`\n{}", arg, USAGE),
+        }
+    }
+    let days = days(year).with_context(|| format!("no solvers registered for {}", year))?;
+
+    // Use the same cargo, and so the same toolchain, that built this runner when run through it.
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = process::Command::new(cargo);
+    command
+        .current_dir(workspace_root())
+        .env(YEAR_ENV, year.to_string())
+        .args(["test", "-p", "aoc"]);
+    for (day, _) in days {
+        command.arg("-p").arg(format!("y{}-day-{}", year, day));
+    }
+    let status = command
+        .args(args)
+        .status()
+        .context("could not run cargo test")?;
+    if !status.success() {
+        bail!("the tests for {} failed", year);
+    }
+    Ok(())
+}
+
+/// Rebuild one day and check its answers on its examples and input every time its crate, its
+/// inputs or `common` change, until interrupted.
+fn run_watch(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut year = latest_year();
+    let mut day = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => {
+                let value = args.next().context("--year requires a value")?;
+                year = value.parse::<u16>().context("invalid year")?;
+            }
+            "--day" => {
+                let value = args.next().context("--day requires a value")?;
+                day = Some(value.parse::<u8>().context("invalid day")?);
+            }
+            _ => bail!("unknown argument `{}`\n{}", arg, USAGE),
+        }
+    }
+    let day = day.with_context(|| format!("must specify --day\n{}", USAGE))?;
+
+    let puzzle = Puzzle { year, day };
+    find(puzzle).with_context(|| format!("no solver registered for {}", puzzle))?;
+    // Build with the same cargo as the tests do, for the same reason.
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    watch(&cargo, puzzle)
+}
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        Some("run") => run(parse_run_args(Command::Run, args)?),
+        Some("bench") => run_bench(parse_run_args(Command::Bench, args)?),
+        Some("fetch") => run_fetch(args),
+        Some("new") => run_new(args),
+        Some("submit") => run_submit(args),
+        Some("test") => run_tests(args),
+        Some("watch") => run_watch(args),
+        Some(other) => bail!("unknown command `{}`\n{}", other, USAGE),
+        None => bail!(USAGE),
+    }
+}
@@ -0,0 +1,244 @@
+use crate::answers::{Answers, Expected};
+use crate::day_dir;
+use anyhow::{anyhow, Result};
+use common::{Answer, DynSolver, Input, Puzzle, Solved, Unsolved, Variant};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+/// A day to solve as part of a run over many days, along with the input to solve it from.
+pub struct Job {
+    pub puzzle: Puzzle,
+    pub solver: &'static dyn DynSolver,
+    pub input: Input,
+}
+
+/// How an answer compares with the accepted one recorded in the day's `answers.toml`.
+#[derive(Debug)]
+pub enum Check {
+    Pass,
+    Fail(Expected),
+    /// There's no accepted answer to compare with.
+    Unchecked,
+}
+
+/// One variant of one day, solved as part of a run over many days.
+pub struct Outcome {
+    pub puzzle: Puzzle,
+    pub variant: Variant,
+    pub answer: Result<Answer>,
+    /// How long solving took, not counting reading or parsing the input.
+    pub elapsed: Duration,
+    pub check: Check,
+}
+
+impl Outcome {
+    /// Whether the part is still to be solved, which is left out of the failures so that a run can
+    /// pass while a day is in progress.
+    pub fn unsolved(&self) -> bool {
+        matches!(&self.answer, Err(err) if err.is::<Unsolved>())
+    }
+
+    pub fn failed(&self) -> bool {
+        (self.answer.is_err() && !self.unsolved()) || matches!(self.check, Check::Fail(_))
+    }
+}
+
+/// Solve the |variants| of every one of the |jobs| on a pool of |workers| threads, each taking the
+/// next day that no other thread has started on. The outcomes come back in the order of the jobs,
+/// whichever order they finished in.
+pub fn solve_all(jobs: &[Job], variants: &[Variant], workers: usize) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let finished = Mutex::new(jobs.iter().map(|_| Vec::new()).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(index) else {
+                    return;
+                };
+                let outcomes = solve_job(job, variants);
+                finished.lock().unwrap()[index] = outcomes;
+            });
+        }
+    });
+    finished
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Solve the |variants| of one day from a single parse of its input, and check the answers. A day
+/// that panics only fails itself, rather than taking the whole pool down with it.
+fn solve_job(job: &Job, variants: &[Variant]) -> Vec<Outcome> {
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        let lines = job.input.read_lines()?;
+        job.solver.solve_each(lines, variants)
+    }))
+    .unwrap_or_else(|_| Err(anyhow!("panicked")));
+    let solved = match solved {
+        Ok(solved) => solved,
+        Err(err) => {
+            // Every variant fails for the same reason, so they each get a copy of it.
+            return variants
+                .iter()
+                .map(|variant| Outcome {
+                    puzzle: job.puzzle,
+                    variant: *variant,
+                    answer: Err(anyhow!("{:#}", err)),
+                    elapsed: Duration::ZERO,
+                    check: Check::Unchecked,
+                })
+                .collect();
+        }
+    };
+
+    let answers = Answers::load_if_present(&day_dir(job.puzzle));
+    let stem = match &job.input {
+        Input::File(path) => input_stem(path),
+        Input::Stdin => None,
+    };
+    solved
+        .into_iter()
+        .map(
+            |Solved {
+                 variant,
+                 answer,
+                 elapsed,
+             }| {
+                let expected = match (&answers, stem) {
+                    (Ok(Some(answers)), Some(stem)) => answers.get(stem, variant),
+                    _ => None,
+                };
+                let check = match (&answer, expected) {
+                    (Ok(answer), Some(expected)) if expected.matches(answer) => Check::Pass,
+                    (Ok(_), Some(expected)) => Check::Fail(expected.clone()),
+                    _ => Check::Unchecked,
+                };
+                // An answers file that can't be read is as bad as a wrong answer.
+                let answer = match &answers {
+                    Err(err) => Err(anyhow!("{:#}", err)),
+                    Ok(_) => answer,
+                };
+                Outcome {
+                    puzzle: job.puzzle,
+                    variant,
+                    answer,
+                    elapsed,
+                    check,
+                }
+            },
+        )
+        .collect()
+}
+
+const HEADINGS: [&str; 5] = ["puzzle", "part", "answer", "time", "check"];
+
+/// A table of the |outcomes|, one row each, followed by why each failure failed and then any
+/// multi-line answers, which don't fit in a table cell.
+pub fn summary_table(outcomes: &[Outcome]) -> String {
+    let rows = outcomes
+        .iter()
+        .map(|outcome| {
+            let answer = match &outcome.answer {
+                Ok(Answer::Render(_)) => "(drawn below)".to_string(),
+                Ok(answer) => answer.to_string(),
+                Err(_) => "-".to_string(),
+            };
+            let check = match (&outcome.answer, &outcome.check) {
+                _ if outcome.unsolved() => "unsolved",
+                (Err(_), _) => "error",
+                (_, Check::Pass) => "pass",
+                (_, Check::Fail(_)) => "FAIL",
+                (_, Check::Unchecked) => "-",
+            };
+            [
+                outcome.puzzle.to_string(),
+                outcome.variant.to_string(),
+                answer,
+                format!("{:.2?}", outcome.elapsed),
+                check.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let mut widths = HEADINGS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    let headings = HEADINGS.map(str::to_string);
+    for row in std::iter::once(&headings).chain(&rows) {
+        let cells = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+
+    let failures = outcomes
+        .iter()
+        .filter_map(|outcome| {
+            let label = format!("{} part {}", outcome.puzzle, outcome.variant);
+            match (&outcome.answer, &outcome.check) {
+                _ if outcome.unsolved() => None,
+                (Err(err), _) => Some(format!("{} failed: {:#}", label, err)),
+                (Ok(answer), Check::Fail(expected)) => Some(format!(
+                    "{}: expected {:?}, got {:?}",
+                    label, expected, answer
+                )),
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+    if !failures.is_empty() {
+        table.push_str(&format!("\n{}\n", failures.join("\n")));
+    }
+    for outcome in outcomes {
+        if let Ok(answer @ Answer::Render(_)) = &outcome.answer {
+            table.push_str(&format!(
+                "\n{}, part {}:\n{}\n",
+                outcome.puzzle, outcome.variant, answer
+            ));
+        }
+    }
+    table
+}
+
+/// How many of a run's outcomes ended each way.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Tally {
+    pub passed: usize,
+    pub failed: usize,
+    pub unsolved: usize,
+    pub unchecked: usize,
+}
+
+/// Count how each of the |outcomes| ended.
+pub fn tally(outcomes: &[Outcome]) -> Tally {
+    let mut tally = Tally::default();
+    for outcome in outcomes {
+        let count = match outcome.check {
+            _ if outcome.failed() => &mut tally.failed,
+            _ if outcome.unsolved() => &mut tally.unsolved,
+            Check::Pass => &mut tally.passed,
+            _ => &mut tally.unchecked,
+        };
+        *count += 1;
+    }
+    tally
+}
+
+/// The name the answers for the input file at |path| are recorded under in `answers.toml`.
+fn input_stem(path: &Path) -> Option<&str> {
+    path.file_stem().and_then(|stem| stem.to_str())
+}
@@ -0,0 +1,176 @@
+use crate::answers::ANSWERS_FILE;
+use anyhow::{bail, Context, Result};
+use common::Puzzle;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The files of a new day's crate, relative to its directory, with `{year}` and `{day}` standing
+/// in for the puzzle's year and day.
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    (ANSWERS_FILE, include_str!("../templates/answers.toml.tmpl")),
+    // Left empty, to be filled in by `aoc fetch` and from the puzzle's worked example.
+    ("input.txt", ""),
+    ("example.txt", ""),
+];
+
+/// Generate the crate for |puzzle| inside the workspace at |root|, then add it to the workspace's
+/// members and register its solver with this runner, starting a new year if need be. An
+/// `input.txt` that was already fetched is kept. Returns the new crate's directory.
+pub fn new_day(root: &Path, puzzle: Puzzle) -> Result<PathBuf> {
+    let Puzzle { year, day } = puzzle;
+    let dir = root.join(year.to_string()).join(format!("day-{}", day));
+    if dir.join("Cargo.toml").exists() {
+        bail!("{} already exists at {}", puzzle, dir.display());
+    }
+
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+        if path.exists() {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("could not create {}", parent.display()))?;
+        }
+        let text = template
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string());
+        fs::write(&path, text).with_context(|| format!("could not write {}", path.display()))?;
+    }
+
+    edit(&root.join("Cargo.toml"), |manifest| {
+        insert_before(
+            manifest,
+            "members = [",
+            "]",
+            &format!("   \"{}/day-{}\",\n", year, day),
+        )
+    })?;
+    edit(&root.join("aoc").join("Cargo.toml"), |manifest| {
+        insert_before(
+            manifest,
+            "[dependencies]",
+            "\n[",
+            &format!(
+                "y{0}-day-{1} = {{ path = \"../{0}/day-{1}\" }}\n",
+                year, day
+            ),
+        )
+    })?;
+    edit(&root.join("aoc").join("src").join("lib.rs"), |lib| {
+        register_solver(lib, puzzle)
+    })?;
+    Ok(dir)
+}
+
+/// The start of the list of a |year|'s solvers in the runner's `lib.rs`.
+fn days_const(year: u16) -> String {
+    format!("const DAYS_{}: Days = ", year)
+}
+
+/// The start of the list of every year in the runner's `lib.rs`.
+const YEARS_CONST: &str = "pub const YEARS: &[(u16, Days)] = ";
+
+/// The doc comment that opens `YEARS` in the runner's `lib.rs`.
+const YEARS_DOC: &str = "/// Every year with solvers, oldest first.";
+
+/// Add the solver for |puzzle| to the end of its year's list in the runner's |lib|, first adding
+/// that list (and its entry in `YEARS`) if this is the year's first day.
+fn register_solver(lib: &str, puzzle: Puzzle) -> Result<String> {
+    let Puzzle { year, day } = puzzle;
+    let mut lib = lib.to_string();
+    if !lib.contains(&days_const(year)) {
+        lib = insert_before(
+            &lib,
+            "",
+            YEARS_DOC,
+            &format!(
+                "/// Every day of {} with a solver.\n{}&[];\n\n",
+                year,
+                days_const(year)
+            ),
+        )?;
+        let years = lib
+            .match_indices("const DAYS_")
+            .filter_map(|(at, found)| {
+                let rest = &lib[at + found.len()..];
+                rest[..rest.find(':')?].parse::<u16>().ok()
+            })
+            .map(|year| format!("({0}, DAYS_{0})", year))
+            .collect::<Vec<_>>();
+        lib = rewrite_list(&lib, YEARS_CONST, |entries| *entries = years)?;
+    }
+    rewrite_list(&lib, &days_const(year), |entries| {
+        entries.push(format!("({0}, &y{1}_day_{0}::Day{0})", day, year))
+    })
+}
+
+/// Rewrite the `&[...];` list of tuples that follows |start| in |lib| with |change|, laying it out
+/// the way rustfmt would so that the file stays formatted.
+fn rewrite_list(lib: &str, start: &str, change: impl FnOnce(&mut Vec<String>)) -> Result<String> {
+    let from = lib
+        .find(start)
+        .with_context(|| format!("could not find `{}`", start))?
+        + start.len();
+    let to = from
+        + lib[from..]
+            .find("];")
+            .with_context(|| format!("could not find the end of `{}`", start))?
+        + 2;
+    // None of the entries nest, so each one runs from a `(` to the next `)`.
+    let mut entries = lib[from..to]
+        .split(')')
+        .filter_map(|piece| Some(format!("{})", piece[piece.find('(')?..].trim())))
+        .collect::<Vec<_>>();
+    change(&mut entries);
+
+    let line = entries.join(", ");
+    let list = if line.len() <= 60 && start.len() + line.len() + 4 <= 100 {
+        format!("&[{}];", line)
+    } else {
+        let lines = entries
+            .iter()
+            .map(|entry| format!("    {},\n", entry))
+            .collect::<String>();
+        format!("&[\n{}];", lines)
+    };
+    Ok(format!("{}{}{}", &lib[..from], list, &lib[to..]))
+}
+
+/// Rewrite the file at |path| with |change|.
+fn edit(path: &Path, change: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))?;
+    let text = change(&text).with_context(|| format!("could not update {}", path.display()))?;
+    fs::write(path, text).with_context(|| format!("could not write {}", path.display()))
+}
+
+/// Insert |line| into |text| at the start of the line holding the first |end| after |start|, so
+/// that it becomes the last entry of the list or section that |start| opens. If nothing follows
+/// that section, |line| is appended instead.
+fn insert_before(text: &str, start: &str, end: &str, line: &str) -> Result<String> {
+    let from = text
+        .find(start)
+        .with_context(|| format!("could not find `{}`", start))?
+        + start.len();
+    let mut text = text.to_string();
+    let at = match text[from..].find(end) {
+        Some(found) => {
+            from + text[from..from + found]
+                .rfind('\n')
+                .with_context(|| format!("expected a line break before `{}`", end))?
+                + 1
+        }
+        None => {
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+            text.len()
+        }
+    };
+    text.insert_str(at, line);
+    Ok(text)
+}
@@ -0,0 +1,210 @@
+use crate::config::Config;
+use crate::fetch::USER_AGENT;
+use anyhow::{bail, Context, Result};
+use common::{Answer, Puzzle, Variant};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The name of the file, kept alongside each day's `input.txt`, that records what was submitted.
+pub const HISTORY_FILE: &str = "submissions.toml";
+
+/// How the puzzle server judged a submitted answer.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// Everything the puzzle server can say back to a submission. Only a |Judged| answer says anything
+/// about the answer itself, so nothing else is kept in the history.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Response {
+    Judged(Verdict),
+    /// Submitted too soon after the last wrong answer; the server says how long is left, if it can
+    /// be found.
+    RateLimited(Option<String>),
+    /// The part has already been solved, or isn't unlocked yet.
+    WrongLevel,
+}
+
+impl Response {
+    /// Recognise the server's reply from the page it returns.
+    pub fn parse(body: &str) -> Result<Response> {
+        let response = if body.contains("That's the right answer") {
+            Response::Judged(Verdict::Correct)
+        } else if body.contains("You gave an answer too recently") {
+            let wait = body
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Response::RateLimited(wait)
+        } else if body.contains("You don't seem to be solving the right level") {
+            Response::WrongLevel
+        } else if body.contains("your answer is too high") {
+            Response::Judged(Verdict::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Response::Judged(Verdict::TooLow)
+        } else if body.contains("That's not the right answer") {
+            Response::Judged(Verdict::Wrong)
+        } else {
+            bail!("could not make sense of the server's response");
+        };
+        Ok(response)
+    }
+}
+
+/// One answer that was submitted, and how it was judged.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Parts {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    a: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    b: Vec<Submission>,
+}
+
+/// Every judged submission for a day, so that known-bad answers are never sent again.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    parts: Parts,
+}
+
+impl History {
+    /// Load the history from a day's directory, starting an empty one if nothing was submitted.
+    pub fn load(day_dir: &Path) -> Result<History> {
+        let path = day_dir.join(HISTORY_FILE);
+        let parts = match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text)
+                .with_context(|| format!("could not parse {}", path.display()))?,
+            Err(_) if !path.exists() => Parts::default(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("could not read {}", path.display()))
+            }
+        };
+        Ok(History { path, parts })
+    }
+
+    pub fn submissions(&self, variant: Variant) -> &[Submission] {
+        match variant {
+            Variant::A => &self.parts.a,
+            Variant::B => &self.parts.b,
+        }
+    }
+
+    /// Refuse an |answer| that the history already rules out: one that was judged before, or a
+    /// number on the wrong side of a known too-high or too-low bound.
+    pub fn check(&self, variant: Variant, answer: &str) -> Result<()> {
+        for submission in self.submissions(variant) {
+            if submission.verdict == Verdict::Correct {
+                bail!(
+                    "part {} was already solved with {}",
+                    variant,
+                    submission.answer
+                );
+            }
+            if submission.answer == answer {
+                bail!(
+                    "{} was already submitted for part {}, and was {}",
+                    answer,
+                    variant,
+                    submission.verdict
+                );
+            }
+            if let (Ok(answer), Ok(bound)) = (answer.parse::<i64>(), submission.answer.parse()) {
+                match submission.verdict {
+                    Verdict::TooHigh if answer > bound => {
+                        bail!("{} is above {}, which was too high", answer, bound)
+                    }
+                    Verdict::TooLow if answer < bound => {
+                        bail!("{} is below {}, which was too low", answer, bound)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Remember how an |answer| was judged, and write the history back to disk.
+    pub fn record(&mut self, variant: Variant, answer: &str, verdict: Verdict) -> Result<()> {
+        let submissions = match variant {
+            Variant::A => &mut self.parts.a,
+            Variant::B => &mut self.parts.b,
+        };
+        submissions.push(Submission {
+            answer: answer.to_string(),
+            verdict,
+        });
+        let text = toml::to_string(&self.parts).context("could not serialize the history")?;
+        fs::write(&self.path, text)
+            .with_context(|| format!("could not write {}", self.path.display()))
+    }
+}
+
+/// The text an |answer| is submitted as. Renders have to be read by eye, so can't be submitted.
+pub fn answer_text(answer: &Answer) -> Result<String> {
+    match answer {
+        Answer::Int(num) => Ok(num.to_string()),
+        Answer::Str(text) => Ok(text.clone()),
+        Answer::Render(_) => bail!("rendered answers have to be read and submitted by hand"),
+    }
+}
+
+/// Submit the |answer| for one part of |puzzle|, unless the |history| already rules it out, and
+/// record the server's verdict in that history.
+pub fn submit(
+    config: &Config,
+    puzzle: Puzzle,
+    variant: Variant,
+    answer: &Answer,
+    history: &mut History,
+) -> Result<Response> {
+    let answer = answer_text(answer)?;
+    history.check(variant, &answer)?;
+
+    let url = format!(
+        "{}/{}/day/{}/answer",
+        config.base_url.trim_end_matches('/'),
+        puzzle.year,
+        puzzle.day
+    );
+    let level = match variant {
+        Variant::A => "1",
+        Variant::B => "2",
+    };
+    let body = ureq::post(&url)
+        .set("Cookie", &format!("session={}", config.session))
+        .set("User-Agent", USER_AGENT)
+        .send_form(&[("level", level), ("answer", &answer)])
+        .with_context(|| format!("could not submit to {}", url))?
+        .into_string()
+        .with_context(|| format!("could not read the response from {}", url))?;
+
+    let response = Response::parse(&body)?;
+    if let Response::Judged(verdict) = response {
+        history.record(variant, &answer, verdict)?;
+    }
+    Ok(response)
+}
@@ -0,0 +1,212 @@
+use crate::answers::{Answers, Expected};
+use crate::{day_dir, workspace_root};
+use anyhow::{Context, Result};
+use common::{parse_json_line, Answer, Puzzle, Variant};
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// When each watched file was last modified, so that two snapshots differ if any file was changed,
+/// added or removed in between.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Snapshot {
+    modified: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Snapshot {
+    /// Every file under each of the |roots|, leaving out hidden files and build output.
+    pub fn take(roots: &[PathBuf]) -> Snapshot {
+        let mut snapshot = Snapshot::default();
+        for root in roots {
+            snapshot.visit(root);
+        }
+        snapshot
+    }
+
+    fn visit(&mut self, path: &Path) {
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        if name.starts_with('.') || name == "target" {
+            return;
+        }
+        match fs::read_dir(path) {
+            Ok(entries) => {
+                for entry in entries.flatten() {
+                    self.visit(&entry.path());
+                }
+            }
+            Err(_) => {
+                let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok();
+                self.modified.insert(path.to_path_buf(), modified);
+            }
+        }
+    }
+}
+
+/// The inputs a day is run on each time something changes: each of its examples, in order of
+/// name, and then its real input.
+pub fn watched_inputs(day_dir: &Path) -> Vec<PathBuf> {
+    let mut examples = fs::read_dir(day_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let stem = path.file_stem().and_then(|stem| stem.to_str());
+            let extension = path.extension().and_then(|ext| ext.to_str());
+            stem.is_some_and(|stem| stem.starts_with("example")) && extension == Some("txt")
+        })
+        .collect::<Vec<_>>();
+    examples.sort();
+    let input = day_dir.join("input.txt");
+    if input.exists() {
+        examples.push(input);
+    }
+    examples
+}
+
+/// Describe how each variant's answer for the input named |stem| compares with the one accepted
+/// for it, if any. Wrong answers are shown as a diff, with the accepted lines marked `-` and the
+/// lines given marked `+`.
+pub fn report(stem: &str, given: &[(Variant, Answer)], accepted: Option<&Answers>) -> String {
+    let mut text = format!("{}:\n", stem);
+    for variant in Variant::ALL {
+        let expected = accepted.and_then(|answers| answers.get(stem, variant));
+        let answer = given
+            .iter()
+            .find(|(given, _)| *given == variant)
+            .map(|(_, answer)| answer);
+        let line = match (answer, expected) {
+            (None, _) => "no answer".to_string(),
+            (Some(answer), Some(expected)) if expected.matches(answer) => noted(answer, "ok"),
+            (Some(answer), Some(expected)) => format!("wrong\n{}", diff(expected, answer)),
+            (Some(answer), None) => noted(answer, "no accepted answer"),
+        };
+        text.push_str(&format!("  part {}: {}\n", variant, line));
+    }
+    text
+}
+
+/// An |answer| followed by a |note| on it, or a render's rows on lines of their own under the note.
+fn noted(answer: &Answer, note: &str) -> String {
+    match answer {
+        Answer::Render(rows) => {
+            let rows = rows.iter().map(|row| format!("      {}", row));
+            format!("({})\n{}", note, rows.collect::<Vec<_>>().join("\n"))
+        }
+        _ => format!("{} ({})", answer, note),
+    }
+}
+
+/// The lines that differ between the |expected| and actual |answer|, paired up by position.
+fn diff(expected: &Expected, answer: &Answer) -> String {
+    let expected = match expected {
+        Expected::Int(num) => vec![num.to_string()],
+        Expected::Str(text) => text
+            .trim_end_matches('\n')
+            .lines()
+            .map(String::from)
+            .collect(),
+    };
+    let actual = answer
+        .to_string()
+        .lines()
+        .map(String::from)
+        .collect::<Vec<_>>();
+    let mut lines = Vec::new();
+    for index in 0..expected.len().max(actual.len()) {
+        let (old, new) = (expected.get(index), actual.get(index));
+        if old == new {
+            lines.push(format!("      {}", old.unwrap()));
+            continue;
+        }
+        if let Some(old) = old {
+            lines.push(format!("    - {}", old));
+        }
+        if let Some(new) = new {
+            lines.push(format!("    + {}", new));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Rebuild the crate for |puzzle| with |cargo|, then run it on each of the day's inputs and report
+/// how its answers compare with the accepted ones. Build failures and errors from the day are
+/// reported rather than returned, so that watching carries on.
+pub fn check_day(cargo: &OsString, puzzle: Puzzle) -> Result<String> {
+    let package = format!("y{}-day-{}", puzzle.year, puzzle.day);
+    let build = Command::new(cargo)
+        .current_dir(workspace_root())
+        .args(["build", "-q", "-p", &package])
+        .output()
+        .context("could not run cargo build")?;
+    if !build.status.success() {
+        return Ok(format!(
+            "{} failed to build:\n{}",
+            package,
+            String::from_utf8_lossy(&build.stderr)
+        ));
+    }
+
+    let dir = day_dir(puzzle);
+    let accepted = match Answers::load_if_present(&dir) {
+        Ok(accepted) => accepted,
+        Err(err) => return Ok(format!("{:#}\n", err)),
+    };
+    let mut text = String::new();
+    for input in watched_inputs(&dir) {
+        let run = Command::new(cargo)
+            .current_dir(workspace_root())
+            // Why a part failed matters here, not where in the day's binary the error came out.
+            .env("RUST_LIB_BACKTRACE", "0")
+            .args(["run", "-q", "-p", &package, "--"])
+            .arg(&input)
+            // Some days answer only one part unless told otherwise.
+            .args(["--part", "both", "--format", "json"])
+            .output()
+            .context("could not run cargo run")?;
+        let given = String::from_utf8_lossy(&run.stdout)
+            .lines()
+            .filter_map(|line| parse_json_line(line).ok())
+            .map(|(_, variant, answer, _)| (variant, answer))
+            .collect::<Vec<_>>();
+        let stem = input
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("");
+        text.push_str(&report(stem, &given, accepted.as_ref()));
+        if !run.status.success() {
+            let errors = String::from_utf8_lossy(&run.stderr);
+            for line in errors.lines() {
+                text.push_str(&format!("  {}\n", line));
+            }
+        }
+    }
+    Ok(text)
+}
+
+/// Check |puzzle| whenever its crate, its inputs or `common` change, until interrupted.
+pub fn watch(cargo: &OsString, puzzle: Puzzle) -> Result<()> {
+    let roots = [day_dir(puzzle), workspace_root().join("common").join("src")];
+    let mut last = None;
+    loop {
+        let snapshot = Snapshot::take(&roots);
+        if last.as_ref() != Some(&snapshot) {
+            // Snapshot before checking, so that edits made while checking trigger another check.
+            last = Some(snapshot);
+            print!("\x1b[2J\x1b[Hchecking {}...\n\n", puzzle);
+            print!("{}", check_day(cargo, puzzle)?);
+            println!("\nwatching {} for changes", day_dir(puzzle).display());
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
@@ -0,0 +1,55 @@
+mod support;
+
+use common::viz::{play, Playback, MAX_FPS};
+use common::{Puzzle, Solver, Variant};
+use std::sync::mpsc;
+use support::example_lines;
+
+/// Play |variant| of |solver|'s scene for the example of 2022's |day| to the end without any keys,
+/// returning the last frame drawn.
+fn last_frame<S: Solver>(solver: &S, day: u8, variant: Variant) -> String {
+    let parsed = solver
+        .parse(example_lines(Puzzle { year: 2022, day }))
+        .unwrap();
+    let mut scene = solver.scene(&parsed, variant).unwrap();
+
+    // Only draw every thousandth step, so that the whole simulation plays back quickly.
+    let playback = Playback {
+        fps: MAX_FPS,
+        skip: 999,
+        paused: false,
+    };
+    let (_, keys) = mpsc::channel();
+    let mut out = Vec::new();
+    let played = play(scene.as_mut(), playback, &keys, &mut out).unwrap();
+    assert!(played.finished);
+
+    let out = String::from_utf8(out).unwrap();
+    out.rsplit("\x1b[H").next().unwrap().replace("\x1b[K", "")
+}
+
+#[test]
+fn crate_moves_end_with_the_answer_on_top() {
+    let frame = last_frame(&y2022_day_5::Day5, 5, Variant::A);
+    assert!(frame.starts_with("        [Z]\n        [N]\n        [D]\n[C] [M] [P]\n 1   2   3"));
+    assert!(frame.contains("move 1 from 1 to 2 (4 of 4)"));
+
+    let frame = last_frame(&y2022_day_5::Day5, 5, Variant::B);
+    assert!(frame.starts_with("        [D]\n        [N]\n        [Z]\n[M] [C] [P]"));
+}
+
+#[test]
+fn rope_tail_visits_as_many_positions_as_the_answer() {
+    let frame = last_frame(&y2022_day_9::Day9, 9, Variant::A);
+    assert!(frame.contains("move 8 of 8, tail visited 13 positions"));
+}
+
+#[test]
+fn sand_settles_as_many_grains_as_the_answer() {
+    let frame = last_frame(&y2022_day_14::Day14, 14, Variant::A);
+    assert!(frame.contains("24 grains of sand at rest"));
+    assert!(!frame.contains('~'));
+
+    let frame = last_frame(&y2022_day_14::Day14, 14, Variant::B);
+    assert!(frame.contains("93 grains of sand at rest"));
+}
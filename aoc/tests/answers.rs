@@ -0,0 +1,79 @@
+use aoc::answers::Answers;
+use aoc::{all_days, day_dir, find, YEAR_ENV};
+use common::{read_input_file_lines, read_lines, Puzzle, Variant};
+use std::fs;
+
+/// Run every registered day against each of its input files picked by |select|, and describe each
+/// variant that doesn't produce the accepted answer recorded in the day's `answers.toml`. Only the
+/// days of the year in `AOC_YEAR` are run, if it's set.
+fn check(select: impl Fn(&str) -> bool) -> Vec<String> {
+    let year = std::env::var(YEAR_ENV)
+        .ok()
+        .map(|year| year.parse::<u16>().unwrap());
+    let days = all_days().filter(|(puzzle, _)| year.is_none_or(|year| year == puzzle.year));
+    let mut failures = Vec::new();
+    for (puzzle, solver) in days {
+        let answers = Answers::load(&day_dir(puzzle)).unwrap();
+        let inputs = answers
+            .inputs()
+            .filter(|input| select(input))
+            .collect::<Vec<_>>();
+        if inputs.is_empty() {
+            failures.push(format!("{}: no matching inputs in answers.toml", puzzle));
+        }
+        for input in inputs {
+            let path = day_dir(puzzle).join(format!("{}.txt", input));
+            for variant in [Variant::A, Variant::B] {
+                let Some(expected) = answers.get(input, variant) else {
+                    continue;
+                };
+                let lines = read_input_file_lines(&path).unwrap();
+                match solver.solve(lines, variant) {
+                    Ok(answer) if expected.matches(&answer) => {}
+                    Ok(answer) => failures.push(format!(
+                        "{} part {} ({}): expected {:?}, got {:?}",
+                        puzzle, variant, input, expected, answer
+                    )),
+                    Err(err) => failures.push(format!(
+                        "{} part {} ({}): {:#}",
+                        puzzle, variant, input, err
+                    )),
+                }
+            }
+        }
+    }
+    failures
+}
+
+#[test]
+fn every_day_matches_its_accepted_answers() {
+    let failures = check(|input| input == "input");
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn every_day_solves_its_examples() {
+    let failures = check(|input| input.starts_with("example"));
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn normalizing_days_solve_examples_saved_by_other_editors() {
+    // The days that opt in to having their input normalized.
+    for day in [6, 7, 13] {
+        let puzzle = Puzzle { year: 2022, day };
+        let solver = find(puzzle).unwrap();
+        let answers = Answers::load(&day_dir(puzzle)).unwrap();
+        let example = fs::read_to_string(day_dir(puzzle).join("example.txt")).unwrap();
+        // A byte order mark, CRLF line endings, trailing spaces and extra blank lines at the end.
+        let saved = format!("\u{feff}{}\r\n \r\n\r\n", example.replace('\n', " \r\n"));
+        for variant in Variant::ALL {
+            let Some(expected) = answers.get("example", variant) else {
+                continue;
+            };
+            let lines = read_lines(saved.as_bytes()).unwrap();
+            let answer = solver.solve(lines, variant).unwrap();
+            assert!(expected.matches(&answer), "{} part {}", puzzle, variant);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use aoc::fetch::fetch;
+use common::Puzzle;
+use std::fs;
+use support::{config, scratch_dir, serve_once, UNREACHABLE};
+
+mod support;
+
+const PUZZLE: Puzzle = Puzzle {
+    year: 2022,
+    day: 15,
+};
+
+#[test]
+fn fetches_and_caches_the_input() {
+    let (base_url, server) = serve_once(200, "1\n2\n3\n");
+    let input = scratch_dir("fetch-new").join("day-15").join("input.txt");
+
+    fetch(&config(&base_url), PUZZLE, &input).unwrap();
+
+    let seen = server.join().unwrap();
+    assert_eq!(seen.method, "GET");
+    assert_eq!(seen.url, "/2022/day/15/input");
+    assert_eq!(seen.cookie.as_deref(), Some("session=secret"));
+    assert_eq!(fs::read_to_string(&input).unwrap(), "1\n2\n3\n");
+}
+
+#[test]
+fn refuses_to_refetch_a_cached_input() {
+    let input = scratch_dir("fetch-cached").join("input.txt");
+    fs::write(&input, "cached\n").unwrap();
+
+    // Nothing listens here, so this would fail differently if a request were made.
+    let err = fetch(&config(UNREACHABLE), PUZZLE, &input).unwrap_err();
+
+    assert!(err.to_string().contains("already cached"), "{:#}", err);
+    assert_eq!(fs::read_to_string(&input).unwrap(), "cached\n");
+}
+
+#[test]
+fn replaces_an_empty_placeholder() {
+    let (base_url, server) = serve_once(200, "fresh\n");
+    let input = scratch_dir("fetch-placeholder").join("input.txt");
+    fs::write(&input, "").unwrap();
+
+    fetch(&config(&base_url), PUZZLE, &input).unwrap();
+
+    server.join().unwrap();
+    assert_eq!(fs::read_to_string(&input).unwrap(), "fresh\n");
+}
+
+#[test]
+fn leaves_no_cache_behind_when_the_server_refuses() {
+    let (base_url, server) = serve_once(400, "Please log in to get your puzzle input.");
+    let input = scratch_dir("fetch-refused").join("input.txt");
+
+    assert!(fetch(&config(&base_url), PUZZLE, &input).is_err());
+
+    server.join().unwrap();
+    assert!(!input.exists());
+}
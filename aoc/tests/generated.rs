@@ -0,0 +1,581 @@
+use aoc::find;
+use common::gen::{generator, Rng};
+use common::{Answer, Puzzle, Solved, Variant};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::panic::{self, AssertUnwindSafe};
+
+/// A slow but simple solution to a day, to check the real one against. Each part's answer comes
+/// back as None if the reference can't tell what it should be, like for a part that hasn't been
+/// solved yet.
+type Reference = fn(&[String]) -> [Option<Answer>; 2];
+
+/// Every day of 2022, along with its reference solution.
+const REFERENCES: &[(u8, Reference)] = &[
+    (1, day1),
+    (2, day2),
+    (3, day3),
+    (4, day4),
+    (5, day5),
+    (6, day6),
+    (7, day7),
+    (8, day8),
+    (9, day9),
+    (10, day10),
+    (11, day11),
+    (12, day12),
+    (13, day13),
+    (14, day14),
+];
+
+/// How many inputs to generate for each day, and how big they get.
+const SEEDS: u64 = 24;
+const SIZES: [usize; 8] = [1, 2, 3, 5, 8, 13, 21, 34];
+
+#[test]
+fn every_day_agrees_with_its_reference_on_generated_inputs() {
+    let mut failures = Vec::new();
+    for &(day, reference) in REFERENCES {
+        let puzzle = Puzzle { year: 2022, day };
+        let generate = generator(puzzle).unwrap();
+        let solver = find(puzzle).unwrap();
+        for seed in 0..SEEDS {
+            let size = SIZES[seed as usize % SIZES.len()];
+            let lines = generate(&mut Rng::new(seed), size);
+            let label = format!("{} (seed {}, size {})", puzzle, seed, size);
+            let expected = reference(&lines);
+            let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+                solver.solve_each(lines.clone(), &Variant::ALL)
+            }));
+            let solved = match solved {
+                Ok(Ok(solved)) => solved,
+                Ok(Err(err)) => {
+                    failures.push(format!("{}: {:#}", label, err));
+                    continue;
+                }
+                Err(_) => {
+                    failures.push(format!("{}: panicked", label));
+                    continue;
+                }
+            };
+            for (
+                Solved {
+                    variant, answer, ..
+                },
+                expected,
+            ) in solved.into_iter().zip(expected)
+            {
+                let Some(expected) = expected else {
+                    continue;
+                };
+                match answer {
+                    Ok(answer) if answer == expected => {}
+                    Ok(answer) => failures.push(format!(
+                        "{} part {}: expected {:?}, got {:?}",
+                        label, variant, expected, answer
+                    )),
+                    Err(err) => failures.push(format!("{} part {}: {:#}", label, variant, err)),
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn generates_the_same_input_from_the_same_seed() {
+    for &(day, _) in REFERENCES {
+        let generate = generator(Puzzle { year: 2022, day }).unwrap();
+        let first = generate(&mut Rng::new(7), 10);
+        assert!(!first.is_empty());
+        assert_eq!(generate(&mut Rng::new(7), 10), first, "day {}", day);
+    }
+}
+
+fn int(num: impl TryInto<i64>) -> Option<Answer> {
+    Some(Answer::Int(num.try_into().ok()?))
+}
+
+/// The lines between blank lines.
+fn groups(lines: &[String]) -> Vec<&[String]> {
+    lines
+        .split(|line| line.is_empty())
+        .filter(|group| !group.is_empty())
+        .collect()
+}
+
+fn day1(lines: &[String]) -> [Option<Answer>; 2] {
+    let mut totals = groups(lines)
+        .iter()
+        .map(|group| group.iter().map(|line| line.parse::<u32>().unwrap()).sum())
+        .collect::<Vec<u32>>();
+    totals.sort_by(|a, b| b.cmp(a));
+    [int(totals[0]), int(totals.iter().take(3).sum::<u32>())]
+}
+
+fn day2(lines: &[String]) -> [Option<Answer>; 2] {
+    let (mut a, mut b) = (0, 0);
+    for line in lines {
+        let bytes = line.as_bytes();
+        let them = (bytes[0] - b'A') as i64;
+        let code = (bytes[2] - b'X') as i64;
+        // Rock, paper and scissors are 0, 1 and 2, and each beats the one before it.
+        let outcome = (code - them + 1).rem_euclid(3);
+        a += code + 1 + 3 * outcome;
+        let me = (them + code - 1).rem_euclid(3);
+        b += me + 1 + 3 * code;
+    }
+    [int(a), int(b)]
+}
+
+fn priority(item: char) -> u32 {
+    match item {
+        'a'..='z' => item as u32 - 'a' as u32 + 1,
+        _ => item as u32 - 'A' as u32 + 27,
+    }
+}
+
+fn day3(lines: &[String]) -> [Option<Answer>; 2] {
+    let a = lines
+        .iter()
+        .map(|line| {
+            let (first, second) = line.split_at(line.len() / 2);
+            priority(first.chars().find(|item| second.contains(*item)).unwrap())
+        })
+        .sum::<u32>();
+    let b = lines
+        .chunks(3)
+        .map(|group| {
+            let badge = group[0]
+                .chars()
+                .find(|item| group[1].contains(*item) && group[2].contains(*item));
+            priority(badge.unwrap())
+        })
+        .sum::<u32>();
+    [int(a), int(b)]
+}
+
+fn day4(lines: &[String]) -> [Option<Answer>; 2] {
+    let pairs = lines
+        .iter()
+        .map(|line| {
+            let nums = line
+                .split([',', '-'])
+                .map(|num| num.parse::<u32>().unwrap())
+                .collect::<Vec<_>>();
+            (nums[0], nums[1], nums[2], nums[3])
+        })
+        .collect::<Vec<_>>();
+    let contained = pairs
+        .iter()
+        .filter(|(a, b, c, d)| (a <= c && d <= b) || (c <= a && b <= d))
+        .count();
+    let overlapping = pairs.iter().filter(|(a, b, c, d)| a <= d && c <= b).count();
+    [int(contained), int(overlapping)]
+}
+
+fn day5(lines: &[String]) -> [Option<Answer>; 2] {
+    let blank = lines.iter().position(|line| line.is_empty()).unwrap();
+    let (drawing, numbers) = (&lines[..blank - 1], &lines[blank - 1]);
+    let count = numbers.split_whitespace().count();
+    let mut stacks = vec![Vec::new(); count];
+    for row in drawing.iter().rev() {
+        for (stack, crates) in stacks.iter_mut().enumerate() {
+            match row.chars().nth(1 + 4 * stack) {
+                Some(' ') | None => {}
+                Some(name) => crates.push(name),
+            }
+        }
+    }
+    let moves = lines[blank + 1..]
+        .iter()
+        .map(|line| {
+            let nums = line
+                .split(' ')
+                .filter_map(|word| word.parse::<usize>().ok())
+                .collect::<Vec<_>>();
+            (nums[0], nums[1] - 1, nums[2] - 1)
+        })
+        .collect::<Vec<_>>();
+    let restack = |one_at_a_time: bool| {
+        let mut stacks = stacks.clone();
+        for &(num, src, dest) in &moves {
+            let at = stacks[src].len() - num;
+            let mut lifted = stacks[src].split_off(at);
+            if one_at_a_time {
+                lifted.reverse();
+            }
+            stacks[dest].extend(lifted);
+        }
+        let tops = stacks.iter().map(|stack| *stack.last().unwrap());
+        Some(Answer::Str(tops.collect()))
+    };
+    [restack(true), restack(false)]
+}
+
+fn day6(lines: &[String]) -> [Option<Answer>; 2] {
+    let stream = lines[0].chars().collect::<Vec<_>>();
+    let marker = |size: usize| {
+        let start = (0..stream.len()).find(|&start| {
+            let window = &stream[start..(start + size).min(stream.len())];
+            window.len() == size && window.iter().collect::<HashSet<_>>().len() == size
+        });
+        int(start.unwrap() + size)
+    };
+    [marker(4), marker(14)]
+}
+
+fn day7(lines: &[String]) -> [Option<Answer>; 2] {
+    let mut path = Vec::new();
+    let mut sizes = HashMap::<Vec<String>, usize>::new();
+    for line in lines {
+        let words = line.split(' ').collect::<Vec<_>>();
+        match words[..] {
+            ["$", "cd", "/"] => path.clear(),
+            ["$", "cd", ".."] => {
+                path.pop();
+            }
+            ["$", "cd", dir] => path.push(dir.to_string()),
+            ["$", "ls"] => {}
+            ["dir", dir] => {
+                let mut inner = path.clone();
+                inner.push(dir.to_string());
+                sizes.entry(inner).or_default();
+            }
+            [size, _] => {
+                let size = size.parse::<usize>().unwrap();
+                for depth in 0..=path.len() {
+                    *sizes.entry(path[..depth].to_vec()).or_default() += size;
+                }
+            }
+            _ => panic!("unexpected line {}", line),
+        }
+    }
+    let small = sizes
+        .values()
+        .filter(|&&size| size <= 100_000)
+        .sum::<usize>();
+    let needed = sizes[&Vec::new()] - 40_000_000;
+    let freed = sizes.values().filter(|&&size| size >= needed).min();
+    [int(small), int(*freed.unwrap())]
+}
+
+fn day8(lines: &[String]) -> [Option<Answer>; 2] {
+    let trees = lines
+        .iter()
+        .map(|line| line.bytes().map(|height| height - b'0').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let (height, width) = (trees.len(), trees[0].len());
+    let mut visible = 0;
+    let mut best = 0;
+    for row in 0..height {
+        for col in 0..width {
+            let tree = trees[row][col];
+            let lines_of_sight: [Vec<u8>; 4] = [
+                (0..col).rev().map(|col| trees[row][col]).collect(),
+                (col + 1..width).map(|col| trees[row][col]).collect(),
+                (0..row).rev().map(|row| trees[row][col]).collect(),
+                (row + 1..height).map(|row| trees[row][col]).collect(),
+            ];
+            if lines_of_sight
+                .iter()
+                .any(|line| line.iter().all(|&other| other < tree))
+            {
+                visible += 1;
+            }
+            let score = lines_of_sight
+                .iter()
+                .map(|line| match line.iter().position(|&other| other >= tree) {
+                    Some(blocker) => blocker + 1,
+                    None => line.len(),
+                })
+                .product::<usize>();
+            best = best.max(score);
+        }
+    }
+    [int(visible), int(best)]
+}
+
+fn day9(lines: &[String]) -> [Option<Answer>; 2] {
+    let (mut head, mut tail) = ((0i32, 0i32), (0i32, 0i32));
+    let mut visited = HashSet::from([tail]);
+    for line in lines {
+        let (direction, steps) = line.split_once(' ').unwrap();
+        for _ in 0..steps.parse::<u32>().unwrap() {
+            match direction {
+                "U" => head.1 += 1,
+                "D" => head.1 -= 1,
+                "L" => head.0 -= 1,
+                _ => head.0 += 1,
+            }
+            if (head.0 - tail.0).abs() > 1 || (head.1 - tail.1).abs() > 1 {
+                tail.0 += (head.0 - tail.0).signum();
+                tail.1 += (head.1 - tail.1).signum();
+                visited.insert(tail);
+            }
+        }
+    }
+    // Part b hasn't been solved yet.
+    [int(visited.len()), None]
+}
+
+fn day10(lines: &[String]) -> [Option<Answer>; 2] {
+    // The value of X during each cycle, starting from the first.
+    let mut during = Vec::new();
+    let mut x = 1i64;
+    for line in lines {
+        during.push(x);
+        if let Some(add) = line.strip_prefix("addx ") {
+            during.push(x);
+            x += add.parse::<i64>().unwrap();
+        }
+    }
+    let strength = (20..=220)
+        .step_by(40)
+        .map(|cycle| cycle as i64 * during[cycle - 1])
+        .sum::<i64>();
+    let screen = (0..6)
+        .map(|row| {
+            (0..40)
+                .map(
+                    |col| match (col - during[row * 40 + col as usize]).abs() <= 1 {
+                        true => '#',
+                        false => '.',
+                    },
+                )
+                .collect::<String>()
+        })
+        .collect();
+    [int(strength), Some(Answer::Render(screen))]
+}
+
+struct Monkey {
+    items: VecDeque<u64>,
+    operation: Vec<String>,
+    divisor: u64,
+    throws: [usize; 2],
+}
+
+/// The product of the two highest counts of inspections over |rounds|, or None if our worry ever
+/// gets too large to count.
+fn monkey_business(
+    monkeys: &[Monkey],
+    rounds: usize,
+    relieve: impl Fn(u64) -> u64,
+) -> Option<Answer> {
+    let mut held = monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect::<Vec<_>>();
+    let mut inspected = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for (index, monkey) in monkeys.iter().enumerate() {
+            while let Some(worry) = held[index].pop_front() {
+                inspected[index] += 1;
+                let operand = |word: &str| match word {
+                    "old" => worry,
+                    _ => word.parse().unwrap(),
+                };
+                let (a, b) = (operand(&monkey.operation[0]), operand(&monkey.operation[2]));
+                let worry = match monkey.operation[1].as_str() {
+                    "+" => a.checked_add(b)?,
+                    _ => a.checked_mul(b)?,
+                };
+                let worry = relieve(worry);
+                let to = monkey.throws[usize::from(!worry.is_multiple_of(monkey.divisor))];
+                held[to].push_back(worry);
+            }
+        }
+    }
+    inspected.sort();
+    int(inspected.iter().rev().take(2).product::<usize>())
+}
+
+fn day11(lines: &[String]) -> [Option<Answer>; 2] {
+    let monkeys = groups(lines)
+        .iter()
+        .map(|notes| {
+            let last_number = |line: &str| line.rsplit(' ').next().unwrap().parse().unwrap();
+            let (_, items) = notes[1].split_once(": ").unwrap();
+            let (_, operation) = notes[2].split_once("= ").unwrap();
+            Monkey {
+                items: items
+                    .split(", ")
+                    .map(|item| item.parse().unwrap())
+                    .collect(),
+                operation: operation.split(' ').map(String::from).collect(),
+                divisor: last_number(&notes[3]),
+                throws: [
+                    last_number(&notes[4]) as usize,
+                    last_number(&notes[5]) as usize,
+                ],
+            }
+        })
+        .collect::<Vec<_>>();
+    let modulus = monkeys.iter().map(|monkey| monkey.divisor).product::<u64>();
+    [
+        monkey_business(&monkeys, 20, |worry| worry / 3),
+        monkey_business(&monkeys, 10_000, |worry| worry % modulus),
+    ]
+}
+
+fn day12(lines: &[String]) -> [Option<Answer>; 2] {
+    let grid = lines
+        .iter()
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let height = |cell: char| match cell {
+        'S' => 0,
+        'E' => 25,
+        _ => cell as i32 - 'a' as i32,
+    };
+    let cells = (0..grid.len())
+        .flat_map(|row| (0..grid[0].len()).map(move |col| (row, col)))
+        .collect::<Vec<_>>();
+    let steps_from = |start: (usize, usize)| {
+        let mut steps = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some((row, col)) = queue.pop_front() {
+            if grid[row][col] == 'E' {
+                return Some(steps[&(row, col)]);
+            }
+            let next = [
+                (row.wrapping_sub(1), col),
+                (row + 1, col),
+                (row, col.wrapping_sub(1)),
+                (row, col + 1),
+            ];
+            for (to_row, to_col) in next {
+                let Some(&cell) = grid.get(to_row).and_then(|cells| cells.get(to_col)) else {
+                    continue;
+                };
+                if height(cell) - height(grid[row][col]) <= 1
+                    && !steps.contains_key(&(to_row, to_col))
+                {
+                    steps.insert((to_row, to_col), steps[&(row, col)] + 1);
+                    queue.push_back((to_row, to_col));
+                }
+            }
+        }
+        None
+    };
+    let start = cells.iter().find(|&&(row, col)| grid[row][col] == 'S');
+    let fewest = cells
+        .iter()
+        .filter(|&&(row, col)| height(grid[row][col]) == 0)
+        .filter_map(|&cell| steps_from(cell))
+        .min();
+    [
+        int(steps_from(*start.unwrap()).unwrap()),
+        int(fewest.unwrap()),
+    ]
+}
+
+#[derive(Clone, Debug)]
+enum Packet {
+    Int(u32),
+    List(Vec<Packet>),
+}
+
+fn parse_packet(text: &mut std::iter::Peekable<std::str::Chars>) -> Packet {
+    if text.peek() != Some(&'[') {
+        let mut num = 0;
+        while let Some(digit) = text.peek().and_then(|ch| ch.to_digit(10)) {
+            num = num * 10 + digit;
+            text.next();
+        }
+        return Packet::Int(num);
+    }
+    text.next();
+    let mut items = Vec::new();
+    while text.peek() != Some(&']') {
+        items.push(parse_packet(text));
+        if text.peek() == Some(&',') {
+            text.next();
+        }
+    }
+    text.next();
+    Packet::List(items)
+}
+
+fn compare(left: &Packet, right: &Packet) -> Ordering {
+    match (left, right) {
+        (Packet::Int(left), Packet::Int(right)) => left.cmp(right),
+        (Packet::Int(_), Packet::List(_)) => compare(&Packet::List(vec![left.clone()]), right),
+        (Packet::List(_), Packet::Int(_)) => compare(left, &Packet::List(vec![right.clone()])),
+        (Packet::List(left), Packet::List(right)) => left
+            .iter()
+            .zip(right)
+            .map(|(left, right)| compare(left, right))
+            .find(|order| order.is_ne())
+            .unwrap_or(left.len().cmp(&right.len())),
+    }
+}
+
+fn day13(lines: &[String]) -> [Option<Answer>; 2] {
+    let packets = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_packet(&mut line.chars().peekable()))
+        .collect::<Vec<_>>();
+    let ordered = packets
+        .chunks(2)
+        .enumerate()
+        .filter(|(_, pair)| compare(&pair[0], &pair[1]).is_lt())
+        .map(|(index, _)| index + 1)
+        .sum::<usize>();
+    // Each divider goes after every packet that doesn't sort after it, and the first divider.
+    let place = |divider: &str| {
+        let divider = parse_packet(&mut divider.chars().peekable());
+        1 + packets
+            .iter()
+            .filter(|packet| compare(packet, &divider).is_le())
+            .count()
+    };
+    [int(ordered), int(place("[[2]]") * (place("[[6]]") + 1))]
+}
+
+fn day14(lines: &[String]) -> [Option<Answer>; 2] {
+    let mut rock = HashSet::new();
+    for line in lines {
+        let points = line
+            .split(" -> ")
+            .map(|point| {
+                let (col, row) = point.split_once(',').unwrap();
+                (col.parse::<i32>().unwrap(), row.parse::<i32>().unwrap())
+            })
+            .collect::<Vec<_>>();
+        for ends in points.windows(2) {
+            let ((col, row), (to_col, to_row)) = (ends[0], ends[1]);
+            for col in col.min(to_col)..=col.max(to_col) {
+                for row in row.min(to_row)..=row.max(to_row) {
+                    rock.insert((col, row));
+                }
+            }
+        }
+    }
+    let lowest = rock.iter().map(|&(_, row)| row).max().unwrap();
+    let pour = |floor: Option<i32>| {
+        let mut filled = rock.clone();
+        let mut grains = 0;
+        while !filled.contains(&(500, 0)) {
+            let (mut col, mut row) = (500, 0);
+            loop {
+                if floor.is_none() && row > lowest {
+                    return grains;
+                }
+                let below = [(col, row + 1), (col - 1, row + 1), (col + 1, row + 1)]
+                    .into_iter()
+                    .find(|cell| !filled.contains(cell) && Some(cell.1) != floor);
+                match below {
+                    Some(cell) => (col, row) = cell,
+                    None => break,
+                }
+            }
+            filled.insert((col, row));
+            grains += 1;
+        }
+        grains
+    };
+    [int(pour(None)), int(pour(Some(lowest + 2)))]
+}
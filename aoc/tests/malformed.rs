@@ -0,0 +1,178 @@
+use aoc::find;
+use common::gen::{generator, Rng};
+use common::{Puzzle, Variant};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Inputs that have made a day panic before.
+const REGRESSIONS: &[(u8, &[&str])] = &[
+    (1, &["4294967295", "1"]),
+    (1, &["4294967295", "", "4294967295"]),
+    (4, &["2-4,6-8", "2-4"]),
+    (4, &["2-4,6-x"]),
+    (4, &["2,6-8"]),
+    (5, &["[A]", " 1 ", "", "move 1 from 0 to 1"]),
+    (5, &["[A] [B]", " 1   2 ", "", "move 3 from 1 to 2"]),
+    (7, &["$ cd /"]),
+    (
+        7,
+        &[
+            "$ cd /",
+            "$ ls",
+            "10000000000000000000 a",
+            "10000000000000000000 b",
+        ],
+    ),
+    (7, &[]),
+    (9, &["U 30000", "U 30000"]),
+    (9, &["L 32767", "R 32767", "R 32767"]),
+    (10, &["addx 9223372036854775807", "addx 5"]),
+    (10, &["addx -9223372036854775807", "addx -2", "noop"]),
+    (
+        10,
+        &[
+            "addx 1000000000000000000",
+            "addx 0",
+            "addx 0",
+            "addx 0",
+            "addx 0",
+            "addx 0",
+            "addx 0",
+            "addx 0",
+            "addx 0",
+            "addx 0",
+        ],
+    ),
+    (
+        11,
+        &[
+            "Monkey 0:",
+            "  Starting items: 79",
+            "  Operation: new = old * 19",
+            "  Test: divisible by 0",
+            "    If true: throw to monkey 1",
+            "    If false: throw to monkey 1",
+        ],
+    ),
+    (
+        11,
+        &[
+            "Monkey 0:",
+            "  Starting items: 79",
+            "  Operation: new = old + 1",
+            "  Test: divisible by 4294967296",
+            "    If true: throw to monkey 1",
+            "    If false: throw to monkey 1",
+            "",
+            "Monkey 1:",
+            "  Starting items: 98",
+            "  Operation: new = old + 1",
+            "  Test: divisible by 4294967296",
+            "    If true: throw to monkey 0",
+            "    If false: throw to monkey 0",
+        ],
+    ),
+    (12, &[]),
+    (13, &["[99999999999]", "[1]"]),
+    (
+        13,
+        &[
+            "[[[[[[[[[[[[[[[[[[[[[[[[]]]]]]]]]]]]]]]]]]]]]]]]",
+            "[[[[[[[[[[[[[[[[[[[[[[[[]]]]]]]]]]]]]]]]]]]]]]]]",
+        ],
+    ),
+    (14, &["498,4 -> 498,18446744073709551615"]),
+    (14, &["0,4 -> 18446744073709551615,4"]),
+];
+
+/// Text to splice into lines, chosen to be the sort of thing each parser has to watch out for.
+const SPLICES: &[&str] = &[
+    "",
+    " ",
+    "0",
+    "1",
+    "-1",
+    "99999999999999999999",
+    ",",
+    "-",
+    "[",
+    "]",
+    "$",
+    "old",
+    "é",
+    "\u{feff}",
+];
+
+/// How many mangled inputs to solve for each day.
+const SEEDS: u64 = 1000;
+
+/// Changes a valid input in one small way, so that it's probably no longer valid.
+fn mangle(rng: &mut Rng, lines: &mut Vec<String>) {
+    if lines.is_empty() {
+        lines.push(String::new());
+        return;
+    }
+    let index = rng.range(0..=lines.len() - 1);
+    match rng.range(0..=5) {
+        0 => {
+            lines.remove(index);
+        }
+        1 => lines.insert(index, lines[index].clone()),
+        2 => lines.insert(index, String::new()),
+        3 => {
+            let other = rng.range(0..=lines.len() - 1);
+            lines.swap(index, other);
+        }
+        _ => {
+            let line = &mut lines[index];
+            let bounds = line
+                .char_indices()
+                .map(|(at, _)| at)
+                .chain([line.len()])
+                .collect::<Vec<_>>();
+            let start = *rng.pick(&bounds);
+            let end = *rng.pick(&bounds);
+            let (start, end) = (start.min(end), start.max(end));
+            line.replace_range(start..end, rng.pick(SPLICES));
+        }
+    }
+}
+
+/// Whether solving |lines| as |puzzle| panics, rather than giving answers or errors.
+fn solving_panics(puzzle: Puzzle, lines: &[String]) -> bool {
+    let solver = find(puzzle).unwrap();
+    let solved = || solver.solve_each(lines.to_vec(), &Variant::ALL);
+    panic::catch_unwind(AssertUnwindSafe(solved)).is_err()
+}
+
+#[test]
+fn solves_inputs_that_used_to_panic() {
+    for &(day, input) in REGRESSIONS {
+        let puzzle = Puzzle { year: 2022, day };
+        let lines = input
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+        assert!(!solving_panics(puzzle, &lines), "{}: {:?}", puzzle, lines);
+    }
+}
+
+#[test]
+fn never_panics_solving_mangled_inputs() {
+    let mut failures = Vec::new();
+    for day in 1..=14 {
+        let puzzle = Puzzle { year: 2022, day };
+        let generate = generator(puzzle).unwrap();
+        for seed in 0..SEEDS {
+            let mut rng = Rng::new(seed);
+            let size = rng.range(1..=8);
+            let mut lines = generate(&mut rng, size);
+            for _ in 0..rng.range(1..=4) {
+                mangle(&mut rng, &mut lines);
+            }
+            if solving_panics(puzzle, &lines) {
+                failures.push(format!("{} (seed {}): {:?}", puzzle, seed, lines));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,103 @@
+use anyhow::anyhow;
+use aoc::answers::Expected;
+use aoc::parallel::{solve_all, summary_table, tally, Check, Job, Outcome, Tally};
+use aoc::{all_days, day_dir};
+use common::{Answer, Input, Puzzle, Unsolved, Variant};
+use std::time::Duration;
+
+/// A job for every registered day, solving its example.
+fn examples() -> Vec<Job> {
+    all_days()
+        .map(|(puzzle, solver)| Job {
+            puzzle,
+            solver,
+            input: Input::File(day_dir(puzzle).join("example.txt")),
+        })
+        .collect()
+}
+
+#[test]
+fn solves_every_day_in_order_whatever_the_pool_size() {
+    let jobs = examples();
+    for workers in [1, 3, 64] {
+        let outcomes = solve_all(&jobs, &Variant::ALL, workers);
+        let order = outcomes
+            .iter()
+            .map(|outcome| (outcome.puzzle, outcome.variant))
+            .collect::<Vec<_>>();
+        let expected = jobs
+            .iter()
+            .flat_map(|job| Variant::ALL.map(|variant| (job.puzzle, variant)))
+            .collect::<Vec<_>>();
+        assert_eq!(order, expected);
+
+        // Every example answer that's recorded is checked, and day 9 part b isn't solved yet.
+        for outcome in &outcomes {
+            let label = format!("{} part {}", outcome.puzzle, outcome.variant);
+            match (outcome.puzzle.day, outcome.variant) {
+                (9, Variant::B) => assert!(outcome.unsolved(), "{}", label),
+                _ => assert!(!outcome.unsolved(), "{}", label),
+            }
+            assert!(!outcome.failed(), "{}", label);
+        }
+    }
+}
+
+#[test]
+fn fails_only_the_day_that_cant_be_solved() {
+    let mut jobs = examples();
+    jobs[0].input = Input::File(day_dir(jobs[0].puzzle).join("missing.txt"));
+    let outcomes = solve_all(&jobs, &[Variant::A], 2);
+    let err = outcomes[0].answer.as_ref().unwrap_err();
+    assert!(err.to_string().contains("could not find input file"));
+    assert!(outcomes[1..].iter().all(|outcome| !outcome.failed()));
+}
+
+fn outcome(day: u8, answer: anyhow::Result<Answer>, check: Check) -> Outcome {
+    Outcome {
+        puzzle: Puzzle { year: 2022, day },
+        variant: Variant::A,
+        answer,
+        elapsed: Duration::from_micros(1500),
+        check,
+    }
+}
+
+#[test]
+fn tabulates_and_tallies_outcomes() {
+    let outcomes = [
+        outcome(1, Ok(Answer::Int(24000)), Check::Pass),
+        outcome(2, Ok(Answer::Int(15)), Check::Fail(Expected::Int(12))),
+        outcome(3, Err(anyhow!("parsing: bad line")), Check::Unchecked),
+        outcome(10, Ok(Answer::Render(vec!["#.".into()])), Check::Unchecked),
+        outcome(9, Err(Unsolved(Variant::A).into()), Check::Unchecked),
+    ];
+    assert_eq!(
+        summary_table(&outcomes),
+        [
+            "puzzle       part  answer         time    check",
+            "2022 day 1   a     24000          1.50ms  pass",
+            "2022 day 2   a     15             1.50ms  FAIL",
+            "2022 day 3   a     -              1.50ms  error",
+            "2022 day 10  a     (drawn below)  1.50ms  -",
+            "2022 day 9   a     -              1.50ms  unsolved",
+            "",
+            "2022 day 2 part a: expected Int(12), got Int(15)",
+            "2022 day 3 part a failed: parsing: bad line",
+            "",
+            "2022 day 10, part a:",
+            "#.",
+            "",
+        ]
+        .join("\n")
+    );
+    assert_eq!(
+        tally(&outcomes),
+        Tally {
+            passed: 1,
+            failed: 2,
+            unsolved: 1,
+            unchecked: 1,
+        }
+    );
+}
@@ -0,0 +1,107 @@
+use common::{DynSolver, ParseError, Variant};
+
+/// Solve |lines| with |solver|, expecting the input to be rejected with a |ParseError|.
+fn reject(solver: &dyn DynSolver, lines: &[&str]) -> ParseError {
+    let lines = lines.iter().map(|line| line.to_string()).collect();
+    let err = solver.solve(lines, Variant::A).unwrap_err();
+    match err.downcast::<ParseError>() {
+        Ok(err) => err,
+        Err(err) => panic!("expected a parse error, got: {:#}", err),
+    }
+}
+
+#[test]
+fn points_at_the_offending_character() {
+    let err = reject(&y2022_day_13::Day13, &["[1,[2]]", "[1,[2x]]"]);
+    assert_eq!((err.line, err.column), (2, Some(6)));
+    assert_eq!(
+        err.to_string(),
+        [
+            "parsing: invalid character",
+            " --> line 2, column 6",
+            "  |",
+            "2 | [1,[2x]]",
+            "  |      ^",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn blames_the_whole_line_without_a_column() {
+    let lines = ["1000", "2000", "", "3000", "", "", "", "", "", "", "4x00"];
+    let err = reject(&y2022_day_1::Day1, &lines);
+    assert_eq!(
+        err.to_string(),
+        [
+            "invalid input line: invalid digit found in string",
+            "  --> line 11",
+            "   |",
+            "11 | 4x00",
+            "   | ^^^^",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn counts_lines_across_sections() {
+    let lines = [
+        "    [D]",
+        "[N] [C]",
+        " 1   2",
+        "",
+        "move 1 from 2 to 1",
+        "move one",
+    ];
+    let err = reject(&y2022_day_5::Day5, &lines);
+    assert_eq!((err.line, err.column), (6, None));
+    assert_eq!(err.snippet, "move one");
+
+    let err = reject(
+        &y2022_day_5::Day5,
+        &["[A] [7]", " 1   2", "", "move 1 from 2 to 1"],
+    );
+    assert_eq!((err.line, err.column), (1, Some(6)));
+
+    let err = reject(&y2022_day_12::Day12, &["Sabqponm", "abcryxxl", "accsz?xk"]);
+    assert_eq!((err.line, err.column), (3, Some(6)));
+}
+
+#[test]
+fn trims_long_lines_around_the_column() {
+    let line = format!("{}#{}", "a".repeat(200), "b".repeat(200));
+    let err = ParseError::new("bad", 0, &line).at(200);
+    let rendered = err.to_string();
+    let snippet = rendered.lines().nth(3).unwrap();
+    let carets = rendered.lines().nth(4).unwrap();
+    assert!(snippet.starts_with("1 | ...") && snippet.ends_with("..."));
+    assert_eq!(snippet.find('#'), carets.find('^'));
+}
+
+#[test]
+fn rejects_testing_for_divisibility_by_zero() {
+    let lines = [
+        "Monkey 0:",
+        "  Starting items: 79, 98",
+        "  Operation: new = old * 19",
+        "  Test: divisible by 0",
+        "    If true: throw to monkey 1",
+        "    If false: throw to monkey 1",
+    ];
+    let err = reject(&y2022_day_11::Day11, &lines);
+    assert_eq!((err.line, err.column), (4, Some(23)));
+    assert!(err
+        .to_string()
+        .starts_with("parsing: expected a divisor other than 0"));
+}
+
+#[test]
+fn rejects_lists_nested_too_deeply() {
+    let line = format!("{}{}", "[".repeat(200_000), "]".repeat(200_000));
+    let err = reject(&y2022_day_13::Day13, &[&line, "[]"]);
+    assert_eq!((err.line, err.column), (1, Some(1001)));
+    assert!(err
+        .to_string()
+        .starts_with("parsing: lists nested too deeply"));
+}
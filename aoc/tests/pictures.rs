@@ -0,0 +1,66 @@
+mod support;
+
+use common::grid::Pos;
+use common::image::Image;
+use common::{Puzzle, Solver, Variant};
+use support::example_lines;
+
+/// The picture |solver| draws of |variant| of the example of 2022's |day|.
+fn picture<S: Solver>(solver: &S, day: u8, variant: Variant) -> Image {
+    let parsed = solver
+        .parse(example_lines(Puzzle { year: 2022, day }))
+        .unwrap();
+    solver.picture(&parsed, variant).unwrap()
+}
+
+/// How many of |image|'s pixels are |colour|.
+fn count(image: &Image, colour: [u8; 3]) -> usize {
+    image
+        .pixels
+        .iter()
+        .filter(|(_, pixel)| **pixel == colour)
+        .count()
+}
+
+#[test]
+fn crt_picture_matches_the_rendered_answer() {
+    let image = picture(&y2022_day_10::Day10, 10, Variant::B);
+    assert_eq!((image.width(), image.height()), (40 * 8, 6 * 8));
+
+    // The example draws two lit pixels, then two dark ones, across its first row.
+    let lit = image.pixels[Pos::new(0, 0)];
+    assert_eq!(image.pixels[Pos::new(7, 15)], lit);
+    assert_ne!(image.pixels[Pos::new(7, 16)], lit);
+
+    let parsed = y2022_day_10::Day10.parse(vec!["noop".to_string()]).unwrap();
+    assert!(y2022_day_10::Day10.picture(&parsed, Variant::A).is_err());
+}
+
+#[test]
+fn heightmap_picture_draws_the_path_found() {
+    // Each square is 4 pixels wide, and the path includes both ends.
+    let image = picture(&y2022_day_12::Day12, 12, Variant::A);
+    assert_eq!(count(&image, [220, 32, 32]), (31 + 1) * 16);
+    let image = picture(&y2022_day_12::Day12, 12, Variant::B);
+    assert_eq!(count(&image, [220, 32, 32]), (29 + 1) * 16);
+}
+
+#[test]
+fn sand_picture_shows_each_grain() {
+    let image = picture(&y2022_day_14::Day14, 14, Variant::A);
+    assert_eq!(count(&image, [230, 190, 90]), 24 * 16);
+    let image = picture(&y2022_day_14::Day14, 14, Variant::B);
+    assert_eq!(count(&image, [230, 190, 90]), 93 * 16);
+}
+
+#[test]
+fn forest_picture_picks_out_visible_trees() {
+    let a = picture(&y2022_day_8::Day8, 8, Variant::A);
+    let b = picture(&y2022_day_8::Day8, 8, Variant::B);
+    assert_eq!((a.width(), a.height()), (5 * 4, 5 * 4));
+    let differing = a
+        .pixels
+        .iter()
+        .filter(|(pos, pixel)| b.pixels[*pos] != **pixel);
+    assert_eq!(differing.count(), 21 * 16);
+}
@@ -0,0 +1,111 @@
+use aoc::scaffold::new_day;
+use aoc::workspace_root;
+use common::Puzzle;
+use std::fs;
+use std::path::{Path, PathBuf};
+use support::scratch_dir;
+
+mod support;
+
+/// A scratch workspace holding copies of the real files that a new day is registered in.
+fn scratch_workspace(name: &str) -> PathBuf {
+    let root = scratch_dir(name);
+    for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/lib.rs"] {
+        let copy = root.join(file);
+        fs::create_dir_all(copy.parent().unwrap()).unwrap();
+        fs::copy(workspace_root().join(file), copy).unwrap();
+    }
+    root
+}
+
+fn puzzle(year: u16, day: u8) -> Puzzle {
+    Puzzle { year, day }
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn generates_and_registers_a_day() {
+    let root = scratch_workspace("new-day");
+
+    let dir = new_day(&root, puzzle(2022, 25)).unwrap();
+
+    assert_eq!(dir, root.join("2022/day-25"));
+    assert!(read(&dir.join("Cargo.toml")).contains("name = \"y2022-day-25\""));
+    assert!(read(&dir.join("src/lib.rs")).contains("impl Solver for Day25"));
+    assert!(read(&dir.join("src/main.rs")).contains("use y2022_day_25::Day25;"));
+    assert_eq!(read(&dir.join("input.txt")), "");
+    assert_eq!(read(&dir.join("example.txt")), "");
+    assert!(dir.join("answers.toml").exists());
+
+    let members = read(&root.join("Cargo.toml"));
+    assert!(
+        members.contains("   \"2022/day-14\",\n   \"2022/day-25\",\n]"),
+        "{}",
+        members
+    );
+    let manifest = read(&root.join("aoc/Cargo.toml"));
+    assert!(
+        manifest.contains("y2022-day-25 = { path = \"../2022/day-25\" }\n\n["),
+        "{}",
+        manifest
+    );
+    let lib = read(&root.join("aoc/src/lib.rs"));
+    assert!(
+        lib.contains("    (25, &y2022_day_25::Day25),\n];"),
+        "{}",
+        lib
+    );
+}
+
+#[test]
+fn starts_a_new_year() {
+    let root = scratch_workspace("new-year");
+
+    new_day(&root, puzzle(2023, 1)).unwrap();
+    new_day(&root, puzzle(2023, 2)).unwrap();
+
+    assert!(root.join("2023/day-1/src/lib.rs").exists());
+    let members = read(&root.join("Cargo.toml"));
+    assert!(
+        members.contains("   \"2023/day-1\",\n   \"2023/day-2\",\n]"),
+        "{}",
+        members
+    );
+    let lib = read(&root.join("aoc/src/lib.rs"));
+    assert!(
+        lib.contains(
+            "const DAYS_2023: Days = &[(1, &y2023_day_1::Day1), (2, &y2023_day_2::Day2)];"
+        ),
+        "{}",
+        lib
+    );
+    assert!(
+        lib.contains("pub const YEARS: &[(u16, Days)] = &[(2022, DAYS_2022), (2023, DAYS_2023)];"),
+        "{}",
+        lib
+    );
+}
+
+#[test]
+fn keeps_an_already_fetched_input() {
+    let root = scratch_workspace("new-fetched");
+    fs::create_dir_all(root.join("2022/day-25")).unwrap();
+    fs::write(root.join("2022/day-25/input.txt"), "fetched\n").unwrap();
+
+    new_day(&root, puzzle(2022, 25)).unwrap();
+
+    assert_eq!(read(&root.join("2022/day-25/input.txt")), "fetched\n");
+}
+
+#[test]
+fn refuses_to_replace_an_existing_day() {
+    let root = scratch_workspace("new-existing");
+    new_day(&root, puzzle(2022, 25)).unwrap();
+    let manifest = read(&root.join("Cargo.toml"));
+
+    assert!(new_day(&root, puzzle(2022, 25)).is_err());
+    assert_eq!(read(&root.join("Cargo.toml")), manifest);
+}
@@ -0,0 +1,170 @@
+use aoc::submit::{submit, History, Response, Submission, Verdict};
+use common::{Answer, Puzzle, Variant};
+use std::fs;
+use support::{config, scratch_dir, serve_once, UNREACHABLE};
+
+mod support;
+
+const PUZZLE: Puzzle = Puzzle {
+    year: 2022,
+    day: 15,
+};
+
+const CORRECT: &str = "<article><p>That's the right answer! You are one gold star closer to \
+    collecting enough star fruit.</p></article>";
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. If \
+    you're stuck, make sure you're using the full input data.</p></article>";
+const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.</p>\
+    </article>";
+const WRONG: &str = "<article><p>That's not the right answer. If you're stuck, make sure \
+    you're using the full input data.</p></article>";
+const RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait \
+    after submitting an answer before trying again.  You have 34s left to wait.</p></article>";
+const WRONG_LEVEL: &str = "<article><p>You don't seem to be solving the right level.  Did you \
+    already complete it?</p></article>";
+
+#[test]
+fn recognises_every_response() {
+    let cases = [
+        (CORRECT, Response::Judged(Verdict::Correct)),
+        (TOO_HIGH, Response::Judged(Verdict::TooHigh)),
+        (TOO_LOW, Response::Judged(Verdict::TooLow)),
+        (WRONG, Response::Judged(Verdict::Wrong)),
+        (RATE_LIMITED, Response::RateLimited(Some("34s".to_string()))),
+        (WRONG_LEVEL, Response::WrongLevel),
+    ];
+    for (body, expected) in cases {
+        assert_eq!(Response::parse(body).unwrap(), expected, "{}", body);
+    }
+    assert!(Response::parse("<html>Something else entirely</html>").is_err());
+}
+
+#[test]
+fn posts_the_answer_and_records_the_verdict() {
+    let (base_url, server) = serve_once(200, CORRECT);
+    let dir = scratch_dir("submit-correct");
+    let mut history = History::load(&dir).unwrap();
+
+    let response = submit(
+        &config(&base_url),
+        PUZZLE,
+        Variant::B,
+        &Answer::Int(42),
+        &mut history,
+    )
+    .unwrap();
+
+    let seen = server.join().unwrap();
+    assert_eq!(response, Response::Judged(Verdict::Correct));
+    assert_eq!(seen.method, "POST");
+    assert_eq!(seen.url, "/2022/day/15/answer");
+    assert_eq!(seen.cookie.as_deref(), Some("session=secret"));
+    assert_eq!(seen.body, "level=2&answer=42");
+    assert_eq!(
+        History::load(&dir).unwrap().submissions(Variant::B),
+        [Submission {
+            answer: "42".to_string(),
+            verdict: Verdict::Correct
+        }]
+    );
+}
+
+#[test]
+fn never_resubmits_outside_known_bounds() {
+    let dir = scratch_dir("submit-bounds");
+    let mut history = History::load(&dir).unwrap();
+    let (base_url, server) = serve_once(200, TOO_HIGH);
+    submit(
+        &config(&base_url),
+        PUZZLE,
+        Variant::A,
+        &Answer::Int(100),
+        &mut history,
+    )
+    .unwrap();
+    server.join().unwrap();
+    let (base_url, server) = serve_once(200, TOO_LOW);
+    submit(
+        &config(&base_url),
+        PUZZLE,
+        Variant::A,
+        &Answer::Int(10),
+        &mut history,
+    )
+    .unwrap();
+    server.join().unwrap();
+
+    // Reload, to check that the bounds were kept on disk.
+    let mut history = History::load(&dir).unwrap();
+    for rejected in [100, 150, 10, 5] {
+        let result = submit(
+            &config(UNREACHABLE),
+            PUZZLE,
+            Variant::A,
+            &Answer::Int(rejected),
+            &mut history,
+        );
+        assert!(result.is_err(), "{} was not refused", rejected);
+    }
+    // Within the bounds, the server is asked, and so can't be reached.
+    let err = submit(
+        &config(UNREACHABLE),
+        PUZZLE,
+        Variant::A,
+        &Answer::Int(50),
+        &mut history,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("could not submit"), "{:#}", err);
+    // The other part's bounds are its own.
+    assert!(history.check(Variant::B, "100").is_ok());
+}
+
+#[test]
+fn never_resubmits_a_wrong_answer() {
+    let dir = scratch_dir("submit-wrong");
+    let mut history = History::load(&dir).unwrap();
+    let (base_url, server) = serve_once(200, WRONG);
+    let answer = Answer::Str("CMZ".to_string());
+    submit(
+        &config(&base_url),
+        PUZZLE,
+        Variant::A,
+        &answer,
+        &mut history,
+    )
+    .unwrap();
+    server.join().unwrap();
+
+    assert!(submit(
+        &config(UNREACHABLE),
+        PUZZLE,
+        Variant::A,
+        &answer,
+        &mut history
+    )
+    .is_err());
+    assert!(history.check(Variant::A, "MCD").is_ok());
+}
+
+#[test]
+fn does_not_record_a_rate_limited_submission() {
+    let dir = scratch_dir("submit-rate-limited");
+    let mut history = History::load(&dir).unwrap();
+    let (base_url, server) = serve_once(200, RATE_LIMITED);
+
+    let response = submit(
+        &config(&base_url),
+        PUZZLE,
+        Variant::A,
+        &Answer::Int(7),
+        &mut history,
+    )
+    .unwrap();
+
+    server.join().unwrap();
+    assert_eq!(response, Response::RateLimited(Some("34s".to_string())));
+    assert!(history.submissions(Variant::A).is_empty());
+    assert!(!dir.join("submissions.toml").exists());
+    assert!(fs::read_dir(&dir).unwrap().next().is_none());
+}
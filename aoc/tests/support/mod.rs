@@ -0,0 +1,68 @@
+// Each test binary that includes this module only uses some of its helpers.
+#![allow(dead_code)]
+
+use aoc::config::Config;
+use aoc::day_dir;
+use common::{read_input_file_lines, Puzzle};
+use std::fs;
+use std::path::PathBuf;
+use std::thread::{self, JoinHandle};
+use tiny_http::{Response, Server};
+
+/// What the stand-in server saw of the one request it answered.
+pub struct Seen {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// Start a stand-in puzzle server on a free local port, which answers a single request with
+/// |status| and |body|. Returns the base URL to configure, and a handle that yields the request.
+pub fn serve_once(status: u16, body: &'static str) -> (String, JoinHandle<Seen>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let handle = thread::spawn(move || {
+        let mut request = server.recv().unwrap();
+        let mut seen = Seen {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            cookie: request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Cookie"))
+                .map(|header| header.value.to_string()),
+            body: String::new(),
+        };
+        request.as_reader().read_to_string(&mut seen.body).unwrap();
+        request
+            .respond(Response::from_string(body).with_status_code(status))
+            .unwrap();
+        seen
+    });
+    (base_url, handle)
+}
+
+/// A base URL that nothing listens on, for checking that no request is made at all.
+pub const UNREACHABLE: &str = "http://127.0.0.1:1";
+
+/// A fresh, empty directory for a test to write into.
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// A config that talks to the server at |base_url|.
+pub fn config(base_url: &str) -> Config {
+    Config {
+        session: "secret".to_string(),
+        base_url: base_url.to_string(),
+    }
+}
+
+/// The lines of the example given with |puzzle|.
+pub fn example_lines(puzzle: Puzzle) -> Vec<String> {
+    read_input_file_lines(&day_dir(puzzle).join("example.txt")).unwrap()
+}
@@ -0,0 +1,129 @@
+mod support;
+
+use aoc::answers::Answers;
+use aoc::day_dir;
+use aoc::watch::{report, watched_inputs, Snapshot};
+use common::{json_line, parse_json_line, Answer, Puzzle, Variant};
+use std::fs;
+use std::time::Duration;
+use support::scratch_dir;
+
+fn day(day: u8) -> Puzzle {
+    Puzzle { year: 2022, day }
+}
+
+#[test]
+fn notices_files_being_changed_added_and_removed() {
+    let root = scratch_dir("watch-snapshot");
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/lib.rs"), "").unwrap();
+    let roots = [root.clone()];
+    let before = Snapshot::take(&roots);
+    assert_eq!(Snapshot::take(&roots), before);
+
+    // Build output and hidden files are left alone.
+    fs::create_dir_all(root.join("target")).unwrap();
+    fs::write(root.join("target/build.log"), "").unwrap();
+    fs::write(root.join(".lib.rs.swp"), "").unwrap();
+    assert_eq!(Snapshot::take(&roots), before);
+
+    fs::write(root.join("example-2.txt"), "").unwrap();
+    let added = Snapshot::take(&roots);
+    assert_ne!(added, before);
+    fs::remove_file(root.join("example-2.txt")).unwrap();
+    assert_eq!(Snapshot::take(&roots), before);
+
+    let file = fs::File::options()
+        .write(true)
+        .open(root.join("src/lib.rs"))
+        .unwrap();
+    let modified = fs::metadata(root.join("src/lib.rs"))
+        .unwrap()
+        .modified()
+        .unwrap();
+    file.set_modified(modified + Duration::from_secs(1))
+        .unwrap();
+    assert_ne!(Snapshot::take(&roots), before);
+}
+
+#[test]
+fn runs_the_examples_before_the_input() {
+    let root = scratch_dir("watch-inputs");
+    for name in [
+        "input.txt",
+        "example-2.txt",
+        "example.txt",
+        "notes.md",
+        "answers.toml",
+    ] {
+        fs::write(root.join(name), "").unwrap();
+    }
+    let names = watched_inputs(&root)
+        .iter()
+        .map(|path| path.file_name().unwrap().to_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["example-2.txt", "example.txt", "input.txt"]);
+
+    assert_eq!(
+        watched_inputs(&day_dir(day(1))),
+        [
+            day_dir(day(1)).join("example.txt"),
+            day_dir(day(1)).join("input.txt")
+        ]
+    );
+}
+
+#[test]
+fn reports_answers_against_the_accepted_ones() {
+    let answers = Answers::load(&day_dir(day(1))).unwrap();
+    let given = [
+        (Variant::A, Answer::Int(24000)),
+        (Variant::B, Answer::Int(45001)),
+    ];
+    assert_eq!(
+        report("example", &given, Some(&answers)),
+        "example:\n  part a: 24000 (ok)\n  part b: wrong\n    - 45000\n    + 45001\n"
+    );
+
+    assert_eq!(
+        report("example-2", &given[..1], Some(&answers)),
+        "example-2:\n  part a: 24000 (no accepted answer)\n  part b: no answer\n"
+    );
+}
+
+#[test]
+fn diffs_a_wrong_render_line_by_line() {
+    let answers = Answers::load(&day_dir(day(10))).unwrap();
+    let expected = answers.get("example", Variant::B).unwrap().clone();
+    let aoc::answers::Expected::Str(text) = expected else {
+        panic!("the example's part b should be a render");
+    };
+    let mut rows = text.lines().map(String::from).collect::<Vec<_>>();
+    rows[2] = "#".repeat(40);
+    let given = [(Variant::B, Answer::Render(rows.clone()))];
+
+    let report = report("example", &given, Some(&answers));
+    let lines = report.lines().collect::<Vec<_>>();
+    assert_eq!(lines[1], "  part a: no answer");
+    assert_eq!(lines[2], "  part b: wrong");
+    assert_eq!(lines[3], format!("      {}", rows[0]));
+    assert_eq!(lines[5], format!("    - {}", text.lines().nth(2).unwrap()));
+    assert_eq!(lines[6], format!("    + {}", rows[2]));
+    assert_eq!(lines.len(), 3 + rows.len() + 1);
+}
+
+#[test]
+fn reads_back_answer_lines() {
+    let puzzle = day(10);
+    for answer in [
+        Answer::Int(-3),
+        Answer::Str("CMZ".to_string()),
+        Answer::Render(vec!["#..".to_string(), ".##".to_string()]),
+    ] {
+        let line = json_line(puzzle, Variant::B, &answer, Duration::from_millis(12));
+        let (read, variant, read_answer, elapsed) = parse_json_line(&line).unwrap();
+        assert_eq!((read, variant, read_answer), (puzzle, Variant::B, answer));
+        assert_eq!(elapsed, Duration::from_millis(12));
+    }
+    assert!(parse_json_line("2022 day 10, part b: 13140").is_err());
+}
@@ -0,0 +1,59 @@
+use std::iter::Enumerate;
+
+// A run of non-blank lines from the input, the first of which is at |start| (0-based) in it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Block<T> {
+    pub start: usize,
+    pub lines: Vec<T>,
+}
+
+impl<T: AsRef<str>> Block<T> {
+    // Each line of the block along with its index in the input.
+    pub fn numbered(&self) -> impl DoubleEndedIterator<Item = (usize, &str)> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(offset, line)| (self.start + offset, line.as_ref()))
+    }
+}
+
+// Splits lines into the |Block|s between blank lines as they're read, so works as well over
+// stdin as over a `Vec`. Runs of several blank lines, and blank lines at either end, don't produce
+// empty blocks.
+pub struct Blocks<I> {
+    lines: Enumerate<I>,
+}
+
+impl<I, T> Iterator for Blocks<I>
+where
+    I: Iterator<Item = T>,
+    T: AsRef<str>,
+{
+    type Item = Block<T>;
+
+    fn next(&mut self) -> Option<Block<T>> {
+        let mut block: Option<Block<T>> = None;
+        for (index, line) in self.lines.by_ref() {
+            match (line.as_ref().is_empty(), &mut block) {
+                (true, None) => continue,
+                (true, Some(_)) => break,
+                (false, None) => {
+                    block = Some(Block {
+                        start: index,
+                        lines: vec![line],
+                    })
+                }
+                (false, Some(block)) => block.lines.push(line),
+            }
+        }
+        block
+    }
+}
+
+// The blocks of |lines|: pass `lines.iter()` to borrow them, or collect the result to read them
+// all up front.
+pub fn blocks<I: IntoIterator>(lines: I) -> Blocks<I::IntoIter> {
+    Blocks {
+        lines: lines.into_iter().enumerate(),
+    }
+}
@@ -0,0 +1,148 @@
+// Tools for simulations that are run for far more steps than it takes them to start repeating
+// themselves. Once a state comes round again, every later state is known without simulating it.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::hash::Hash;
+
+// Where a sequence of states starts to repeat: the state at |start| is seen again |length| steps
+// later, and so on forever after.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // The earliest step at which the state is the same as it is at step |n|.
+    pub fn equivalent(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.length,
+        }
+    }
+}
+
+// Brent's algorithm, which finds the cycle in the sequence |state|, |step(state)|, ... while only
+// keeping two states at a time. The sequence must eventually repeat, or this never returns.
+pub fn brent<S: Clone + PartialEq>(state: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the cycle length by racing a hare ahead of a tortoise that jumps to it at each power
+    // of two, until the hare laps it.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = state.clone();
+    let mut hare = step(&state);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Then find where it starts by walking two states |length| apart until they meet.
+    let mut tortoise = state.clone();
+    let mut hare = state;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+// Every state a sequence passed through before it first repeated, with the |Cycle| it repeats in.
+// Keeping the states makes any of them, and anything computed from them, cheap to look up for an
+// arbitrarily late step.
+#[derive(Clone, Debug)]
+pub struct Orbit<S> {
+    pub states: Vec<S>,
+    // None if no state repeated within the steps traced.
+    pub cycle: Option<Cycle>,
+}
+
+impl<S: Clone + Eq + Hash> Orbit<S> {
+    // Follow the sequence |state|, |step(state)|, ... until a state repeats or |limit| states
+    // have been seen, whichever is first.
+    pub fn trace(state: S, mut step: impl FnMut(&S) -> S, limit: usize) -> Orbit<S> {
+        match Orbit::try_trace(state, |state| Ok::<_, Infallible>(step(state)), limit) {
+            Ok(orbit) => orbit,
+            Err(never) => match never {},
+        }
+    }
+
+    // As |trace|, but giving up as soon as |step| fails.
+    pub fn try_trace<E>(
+        state: S,
+        mut step: impl FnMut(&S) -> Result<S, E>,
+        limit: usize,
+    ) -> Result<Orbit<S>, E> {
+        let mut seen = HashMap::new();
+        let mut states = Vec::new();
+        let mut state = state;
+        while states.len() < limit {
+            if let Some(&start) = seen.get(&state) {
+                return Ok(Orbit {
+                    cycle: Some(Cycle {
+                        start,
+                        length: states.len() - start,
+                    }),
+                    states,
+                });
+            }
+            seen.insert(state.clone(), states.len());
+            // Stop without stepping past the last state that's wanted, as that step may fail.
+            let next = match states.len() + 1 < limit {
+                true => Some(step(&state)?),
+                false => None,
+            };
+            states.push(state);
+            match next {
+                Some(next) => state = next,
+                None => break,
+            }
+        }
+        Ok(Orbit {
+            states,
+            cycle: None,
+        })
+    }
+
+    // The state at step |n|, if it's known.
+    pub fn nth(&self, n: usize) -> Option<&S> {
+        match self.cycle {
+            Some(cycle) => self.states.get(cycle.equivalent(n)),
+            None => self.states.get(n),
+        }
+    }
+
+    // How many times each state occurs in the first |n| steps, leaving out those that don't, so
+    // that summing a quantity over the steps is a matter of weighting it by these counts. Without
+    // a cycle, only the states traced are counted.
+    pub fn occurrences(&self, n: usize) -> impl Iterator<Item = (&S, usize)> {
+        let cycle = self.cycle;
+        self.states
+            .iter()
+            .enumerate()
+            .take(n)
+            .map(move |(index, state)| match cycle {
+                Some(cycle) if index >= cycle.start => (state, (n - 1 - index) / cycle.length + 1),
+                _ => (state, 1),
+            })
+    }
+}
+
+// The state after |n| steps of |step| from |state|, skipping whole cycles once a state repeats.
+pub fn fast_forward<S: Clone + Eq + Hash>(state: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    // Without a cycle, all n + 1 states up to step n get traced. A sequence that runs for
+    // |usize::MAX| steps without repeating could never be traced anyway, so the limit saturates.
+    let mut orbit = Orbit::trace(state, step, n.saturating_add(1));
+    let index = orbit.cycle.map_or(n, |cycle| cycle.equivalent(n));
+    orbit.states.swap_remove(index)
+}
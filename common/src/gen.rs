@@ -0,0 +1,72 @@
+// Random but valid puzzle inputs, for checking solvers on far more inputs than the examples and
+// the real input. Each day has a |Generator| whose inputs grow with the size it's given, and the
+// randomness comes from a seeded |Rng|, so that any input that trips a solver up can be made again
+// from its seed.
+
+use crate::Puzzle;
+use std::ops::RangeInclusive;
+
+pub mod y2022;
+
+// Produces the lines of an input for one day. What the size counts depends on the day, like the
+// number of moves or the width of a grid.
+pub type Generator = fn(&mut Rng, usize) -> Vec<String>;
+
+// Every year with generators, along with the generator for each of its days.
+pub const YEARS: &[(u16, &[(u8, Generator)])] = &[(2022, y2022::DAYS)];
+
+// The generator for |puzzle|, if it has one.
+pub fn generator(puzzle: Puzzle) -> Option<Generator> {
+    let (_, days) = YEARS.iter().find(|(year, _)| *year == puzzle.year)?;
+    days.iter()
+        .find(|(day, _)| *day == puzzle.day)
+        .map(|(_, generator)| *generator)
+}
+
+// SplitMix64, which is nowhere near good enough for anything that needs real randomness, but is
+// small, quick and gives the same numbers everywhere for the same seed.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut mixed = self.state;
+        mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        mixed ^ (mixed >> 31)
+    }
+
+    // A number from |range|, which mustn't be empty. The slight bias towards smaller numbers
+    // doesn't matter for making inputs.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "rng: empty range {}..={}", low, high);
+        match (high - low).checked_add(1) {
+            Some(span) => low + (self.next_u64() % span as u64) as usize,
+            None => self.next_u64() as usize,
+        }
+    }
+
+    // True |num| times in every |den|.
+    pub fn chance(&mut self, num: usize, den: usize) -> bool {
+        self.range(1..=den) <= num
+    }
+
+    // One of the |items|, which mustn't be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.range(0..=index));
+        }
+    }
+}
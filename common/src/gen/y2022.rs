@@ -0,0 +1,529 @@
+// Inputs for the puzzles of 2022, made to keep to everything the puzzles promise about their real
+// inputs, like there being a path up the hill in day 12, or enough to delete in day 7.
+
+use super::{Generator, Rng};
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+pub const DAYS: &[(u8, Generator)] = &[
+    (1, day1),
+    (2, day2),
+    (3, day3),
+    (4, day4),
+    (5, day5),
+    (6, day6),
+    (7, day7),
+    (8, day8),
+    (9, day9),
+    (10, day10),
+    (11, day11),
+    (12, day12),
+    (13, day13),
+    (14, day14),
+];
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn letter(rng: &mut Rng, letters: &str) -> char {
+    let letters = letters.as_bytes();
+    *rng.pick(letters) as char
+}
+
+// The calories in each snack carried by |size| elves.
+pub fn day1(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for elf in 0..size.max(1) {
+        if elf > 0 {
+            lines.push(String::new());
+        }
+        for _ in 0..rng.range(1..=12) {
+            lines.push(rng.range(1..=60_000).to_string());
+        }
+    }
+    lines
+}
+
+// A strategy guide of |size| rounds.
+pub fn day2(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size.max(1))
+        .map(|_| format!("{} {}", letter(rng, "ABC"), letter(rng, "XYZ")))
+        .collect()
+}
+
+// The rucksacks of |size| groups of three elves. The halves of each rucksack have exactly one item
+// in common, and the badge is the only item all three elves in a group carry.
+pub fn day3(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for _ in 0..size.max(1) {
+        let items = format!("{}{}", LOWERCASE, UPPERCASE);
+        let badge = letter(rng, &items);
+        let mut group: Vec<HashSet<char>> = Vec::new();
+        for member in 0..3 {
+            let banned = match member {
+                2 => group[0].intersection(&group[1]).copied().collect(),
+                _ => HashSet::new(),
+            };
+            let mut pool = items
+                .chars()
+                .filter(|item| *item != badge && !banned.contains(item))
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut pool);
+            let shared = pool.pop().unwrap();
+            let (first_pool, second_pool) = pool.split_at(pool.len() / 2);
+
+            // Both halves start with a random item, so the badge can take its place in one of them.
+            let half = rng.range(2..=16);
+            let mut halves = [first_pool, second_pool].map(|pool| {
+                let mut half = (1..half).map(|_| *rng.pick(pool)).collect::<Vec<_>>();
+                half.push(shared);
+                half
+            });
+            halves[rng.range(0..=1)][0] = badge;
+            for half in &mut halves {
+                rng.shuffle(half);
+            }
+            let rucksack = halves.concat();
+            group.push(rucksack.iter().copied().collect());
+            lines.push(rucksack.into_iter().collect());
+        }
+    }
+    lines
+}
+
+fn sections(rng: &mut Rng) -> String {
+    let start = rng.range(1..=99);
+    format!("{}-{}", start, rng.range(start..=99))
+}
+
+// The sections assigned to |size| pairs of elves.
+pub fn day4(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size.max(1))
+        .map(|_| format!("{},{}", sections(rng), sections(rng)))
+        .collect()
+}
+
+// A drawing of up to nine stacks of crates, followed by |size| moves between them. No move takes
+// every crate off a stack, so none of them are empty at the end.
+pub fn day5(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut stacks = (0..rng.range(2..=9))
+        .map(|_| {
+            (0..rng.range(1..=8))
+                .map(|_| letter(rng, UPPERCASE))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // Make sure there's a stack to move crates off.
+    stacks[0].push(letter(rng, UPPERCASE));
+
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    let mut lines = (0..height)
+        .rev()
+        .map(|level| {
+            let row = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(name) => format!("[{}]", name),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>();
+            row.join(" ")
+        })
+        .collect::<Vec<_>>();
+    let numbers = (1..=stacks.len())
+        .map(|num| format!(" {} ", num))
+        .collect::<Vec<_>>();
+    lines.push(numbers.join(" "));
+    lines.push(String::new());
+
+    let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+    for _ in 0..size.max(1) {
+        let sources = (0..heights.len())
+            .filter(|&stack| heights[stack] > 1)
+            .collect::<Vec<_>>();
+        let src = *rng.pick(&sources);
+        let dest = (src + rng.range(1..=heights.len() - 1)) % heights.len();
+        let num = rng.range(1..=heights[src] - 1);
+        heights[src] -= num;
+        heights[dest] += num;
+        lines.push(format!("move {} from {} to {}", num, src + 1, dest + 1));
+    }
+    lines
+}
+
+// A datastream of about |size| characters, with a start-of-message marker somewhere in it.
+pub fn day6(rng: &mut Rng, size: usize) -> Vec<String> {
+    // Too few letters for fourteen in a row to be different, so the marker is found where it's put.
+    let letters = &LOWERCASE[..rng.range(3..=13)];
+    let mut stream = (0..size).map(|_| letter(rng, letters)).collect::<Vec<_>>();
+    let mut marker = LOWERCASE.chars().collect::<Vec<_>>();
+    rng.shuffle(&mut marker);
+    let at = rng.range(0..=stream.len());
+    stream.splice(at..at, marker[..14].iter().copied());
+    vec![stream.into_iter().collect()]
+}
+
+// A name that isn't in |taken| yet, which it's then added to.
+fn unique_name(rng: &mut Rng, taken: &mut HashSet<String>, extensions: &[&str]) -> String {
+    loop {
+        let mut name = (0..rng.range(1..=8))
+            .map(|_| letter(rng, LOWERCASE))
+            .collect::<String>();
+        name.push_str(rng.pick(extensions));
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+struct Directory {
+    name: String,
+    files: Vec<(String, usize)>,
+    dirs: Vec<usize>,
+    taken: HashSet<String>,
+}
+
+// A terminal session exploring a filesystem of |size| directories. Altogether the files take up
+// more than the 40000000 that has to be left for the update to fit on the disk, but less than the
+// whole disk.
+pub fn day7(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut dirs = vec![Directory {
+        name: "/".to_string(),
+        files: Vec::new(),
+        dirs: Vec::new(),
+        taken: HashSet::new(),
+    }];
+    for index in 1..size.max(1) {
+        let parent = rng.range(0..=index - 1);
+        let name = unique_name(rng, &mut dirs[parent].taken, &[""]);
+        dirs[parent].dirs.push(index);
+        dirs.push(Directory {
+            name,
+            files: Vec::new(),
+            dirs: Vec::new(),
+            taken: HashSet::new(),
+        });
+    }
+
+    // Keep the small files small enough to leave room for a few big ones to fill the disk up.
+    let counts = dirs
+        .iter()
+        .map(|dir| rng.range(usize::from(dir.dirs.is_empty())..=4))
+        .collect::<Vec<_>>();
+    let largest = (30_000_000 / counts.iter().sum::<usize>().max(1)).clamp(1, 60_000);
+    let mut used = 0;
+    for (dir, count) in dirs.iter_mut().zip(counts) {
+        for _ in 0..count {
+            let name = unique_name(rng, &mut dir.taken, &["", ".txt", ".dat", ".log"]);
+            let size = rng.range(1..=largest);
+            used += size;
+            dir.files.push((name, size));
+        }
+    }
+    let big = rng.range(1..=3);
+    let mut left = rng.range(40_000_001..=69_999_999) - used;
+    for num in (1..=big).rev() {
+        let size = left / num;
+        left -= size;
+        let at = rng.range(0..=dirs.len() - 1);
+        let dir = &mut dirs[at];
+        let name = unique_name(rng, &mut dir.taken, &[".bin"]);
+        dir.files.push((name, size));
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    explore(rng, &dirs, 0, &mut lines);
+    // Sessions tend to stop without climbing back out of the last directory they looked in.
+    if rng.chance(1, 2) {
+        while lines.last().is_some_and(|line| line == "$ cd ..") {
+            lines.pop();
+        }
+    }
+    lines
+}
+
+// List the directory at |index| in |dirs|, then explore each directory in it.
+fn explore(rng: &mut Rng, dirs: &[Directory], index: usize, lines: &mut Vec<String>) {
+    let dir = &dirs[index];
+    let mut listing = dir
+        .dirs
+        .iter()
+        .map(|&child| format!("dir {}", dirs[child].name))
+        .chain(
+            dir.files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut listing);
+    lines.push("$ ls".to_string());
+    lines.extend(listing);
+    for &child in &dir.dirs {
+        lines.push(format!("$ cd {}", dirs[child].name));
+        explore(rng, dirs, child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+// The heights of the trees in a forest up to |size| trees wide and tall.
+pub fn day8(rng: &mut Rng, size: usize) -> Vec<String> {
+    let width = rng.range(1..=size.max(1));
+    (0..rng.range(1..=size.max(1)))
+        .map(|_| (0..width).map(|_| letter(rng, "0123456789")).collect())
+        .collect()
+}
+
+// |size| moves of the head of the rope.
+pub fn day9(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size.max(1))
+        .map(|_| format!("{} {}", letter(rng, "UDLR"), rng.range(1..=20)))
+        .collect()
+}
+
+// The number of cycles the CRT takes to draw the screen once.
+const CRT_CYCLES: usize = 240;
+
+// A program of at least |size| instructions, running for at least as long as it takes to draw the
+// screen. The sprite is kept where it can be seen, at least in part.
+pub fn day10(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut x = 1isize;
+    let mut cycles = 0;
+    while cycles < CRT_CYCLES || lines.len() < size {
+        if rng.chance(1, 3) {
+            lines.push("noop".to_string());
+            cycles += 1;
+            continue;
+        }
+        let mut add = rng.range(0..=20) as isize - 10;
+        if !(-1..=40).contains(&(x + add)) {
+            add = -add;
+        }
+        x += add;
+        lines.push(format!("addx {}", add));
+        cycles += 2;
+    }
+    lines
+}
+
+// The notes on |size| monkeys, up to eight of them. Each tests for a different prime, so our worry
+// can be managed without overflowing, and at most one of them squares our worry.
+pub fn day11(rng: &mut Rng, size: usize) -> Vec<String> {
+    let count = size.clamp(2, 8);
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19];
+    rng.shuffle(&mut primes);
+    let squarer = match rng.chance(1, 2) {
+        true => Some(rng.range(0..=count - 1)),
+        false => None,
+    };
+    let mut lines = Vec::new();
+    for (monkey, prime) in primes.iter().enumerate().take(count) {
+        if monkey > 0 {
+            lines.push(String::new());
+        }
+        let items = (0..rng.range(1..=4))
+            .map(|_| rng.range(50..=99).to_string())
+            .collect::<Vec<_>>();
+        let operation = match (squarer == Some(monkey), rng.chance(1, 2)) {
+            (true, _) => "old * old".to_string(),
+            (false, true) => format!("old + {}", rng.range(1..=8)),
+            (false, false) => format!("old * {}", rng.range(2..=19)),
+        };
+        // Throw to two different monkeys where there are enough of them.
+        let if_true = (monkey + rng.range(1..=count - 1)) % count;
+        let mut if_false = (monkey + rng.range(1..=count - 1)) % count;
+        while count > 2 && if_false == if_true {
+            if_false = (monkey + rng.range(1..=count - 1)) % count;
+        }
+        lines.extend([
+            format!("Monkey {}:", monkey),
+            format!("  Starting items: {}", items.join(", ")),
+            format!("  Operation: new = {}", operation),
+            format!("  Test: divisible by {}", prime),
+            format!("    If true: throw to monkey {}", if_true),
+            format!("    If false: throw to monkey {}", if_false),
+        ]);
+    }
+    lines
+}
+
+// A heightmap up to |size| squares wide and tall, though never too small to climb all the way from
+// `a` to `z`. The start and goal are in opposite corners, with a path between them that never
+// climbs more than one level at a time, and pits dug everywhere else.
+pub fn day12(rng: &mut Rng, size: usize) -> Vec<String> {
+    const TOP: usize = 25;
+    let rows = rng.range(1..=size.max(1));
+    let cols = rng
+        .range(1..=size.max(1))
+        .max((TOP + 2).saturating_sub(rows));
+
+    // Every square is within one level of the squares next to it, and within reach of both the
+    // start, at level 0, and the goal, at the top.
+    let mut heights = vec![vec![0usize; cols]; rows];
+    for row in 0..rows {
+        for col in 0..cols {
+            let to_goal = (rows - 1 - row) + (cols - 1 - col);
+            let mut low = TOP.saturating_sub(to_goal);
+            let mut high = TOP.min(row + col);
+            let mut beside = Vec::new();
+            if row > 0 {
+                beside.push(heights[row - 1][col]);
+            }
+            if col > 0 {
+                beside.push(heights[row][col - 1]);
+            }
+            for height in beside {
+                low = low.max(height.saturating_sub(1));
+                high = high.min(height + 1);
+            }
+            heights[row][col] = rng.range(low..=high);
+        }
+    }
+
+    let mut path = HashSet::from([(0, 0)]);
+    let (mut row, mut col) = (0, 0);
+    while (row, col) != (rows - 1, cols - 1) {
+        match (row + 1 < rows, col + 1 < cols) {
+            (true, true) if rng.chance(1, 2) => row += 1,
+            (true, true) | (false, true) => col += 1,
+            _ => row += 1,
+        }
+        path.insert((row, col));
+    }
+    for (row, heights) in heights.iter_mut().enumerate() {
+        for (col, height) in heights.iter_mut().enumerate() {
+            if !path.contains(&(row, col)) && rng.chance(1, 5) {
+                *height = rng.range(0..=*height);
+            }
+        }
+    }
+
+    let mut lines = heights
+        .iter()
+        .enumerate()
+        .map(|(row, heights)| {
+            heights
+                .iter()
+                .enumerate()
+                .map(|(col, &height)| match (row, col) {
+                    (0, 0) => 'S',
+                    _ if (row, col) == (rows - 1, cols - 1) => 'E',
+                    _ => (b'a' + height as u8) as char,
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    // Move the corners around.
+    if rng.chance(1, 2) {
+        lines.reverse();
+    }
+    if rng.chance(1, 2) {
+        lines = lines
+            .iter()
+            .map(|line| line.chars().rev().collect())
+            .collect();
+    }
+    lines
+}
+
+#[derive(Clone)]
+enum Packet {
+    Int(u32),
+    List(Vec<Packet>),
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Int(num) => write!(f, "{}", num),
+            Packet::List(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+fn packet_list(rng: &mut Rng, depth: usize) -> Vec<Packet> {
+    (0..rng.range(0..=4))
+        .map(|_| match depth < 3 && rng.chance(1, 3) {
+            true => Packet::List(packet_list(rng, depth + 1)),
+            false => Packet::Int(rng.range(0..=10) as u32),
+        })
+        .collect()
+}
+
+// Change one thing somewhere in |items|, so that two packets can be alike up to a point.
+fn tweak(rng: &mut Rng, items: &mut Vec<Packet>) {
+    let at = rng.range(0..=items.len());
+    if at == items.len() {
+        items.push(Packet::Int(rng.range(0..=10) as u32));
+        return;
+    }
+    let replacement = match &mut items[at] {
+        Packet::List(inner) if rng.chance(2, 3) => return tweak(rng, inner),
+        // A number compared with a list of just that number is a case of its own.
+        Packet::Int(num) if rng.chance(1, 2) => Some(Packet::List(vec![Packet::Int(*num)])),
+        _ if rng.chance(1, 2) => Some(Packet::Int(rng.range(0..=10) as u32)),
+        _ => None,
+    };
+    match replacement {
+        Some(packet) => items[at] = packet,
+        None => {
+            items.remove(at);
+        }
+    }
+}
+
+// |size| pairs of packets, many of which differ only somewhere deep inside them.
+pub fn day13(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for pair in 0..size.max(1) {
+        if pair > 0 {
+            lines.push(String::new());
+        }
+        let left = packet_list(rng, 0);
+        let right = match rng.chance(1, 2) {
+            true => {
+                let mut right = left.clone();
+                tweak(rng, &mut right);
+                right
+            }
+            false => packet_list(rng, 0),
+        };
+        let mut pair = [Packet::List(left), Packet::List(right)];
+        rng.shuffle(&mut pair);
+        lines.extend(pair.iter().map(Packet::to_string));
+    }
+    lines
+}
+
+// The scan of |size| paths of rock, spread out to either side of where the sand comes in.
+pub fn day14(rng: &mut Rng, size: usize) -> Vec<String> {
+    let deepest = (4 + 2 * size).min(60);
+    (0..size.max(1))
+        .map(|_| {
+            let mut col = rng.range(470..=530);
+            let mut row = rng.range(1..=deepest);
+            let mut points = vec![format!("{},{}", col, row)];
+            let across = rng.chance(1, 2);
+            for turn in 0..rng.range(1..=4) {
+                let by = rng.range(1..=6);
+                match (turn % 2 == 0) == across {
+                    true if rng.chance(1, 2) && col > 470 => col -= by.min(col - 470),
+                    true => col += by,
+                    false if rng.chance(1, 2) && row > 1 => row -= by.min(row - 1),
+                    false => row += by,
+                }
+                points.push(format!("{},{}", col, row));
+            }
+            points.join(" -> ")
+        })
+        .collect()
+}
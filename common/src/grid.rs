@@ -0,0 +1,281 @@
+use crate::ParseError;
+use anyhow::{bail, Result};
+use std::fmt::{self, Display};
+use std::iter::successors;
+use std::ops::{Index, IndexMut};
+
+// A cell of a |Grid|, counted from the top left.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Pos {
+        Pos { row, col }
+    }
+}
+
+// A step to take from a |Pos|, as `(rows, cols)`.
+pub type Step = (isize, isize);
+
+pub const UP: Step = (-1, 0);
+pub const DOWN: Step = (1, 0);
+pub const LEFT: Step = (0, -1);
+pub const RIGHT: Step = (0, 1);
+
+// The steps to the cells sharing an edge with a cell...
+pub const ORTHOGONAL: [Step; 4] = [UP, DOWN, LEFT, RIGHT];
+
+// ...and to those sharing an edge or a corner.
+pub const ADJACENT: [Step; 8] = [(-1, -1), UP, (-1, 1), LEFT, RIGHT, (1, -1), DOWN, (1, 1)];
+
+// A rectangle of cells, stored a row at a time. Printing a grid prints each cell with its own
+// |Display|, a row per line, so giving the cell type a |Display| that writes a single character
+// is all it takes to draw one.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Pos) -> T) -> Grid<T> {
+        let cells = (0..width * height)
+            .map(|index| cell(Pos::new(index / width, index % width)))
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Build a grid from its |rows|, which must all be as wide as each other.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            bail!(
+                "row {} is {} cells wide, but row 0 is {}",
+                row,
+                rows[row].len(),
+                width
+            );
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    // Build a grid from the character |lines| of the input, with |cell| turning each character
+    // into a cell. The lines must be equally long, and errors from |cell| are pinned to the
+    // character that caused them.
+    pub fn parse<S: AsRef<str>>(
+        lines: &[S],
+        mut cell: impl FnMut(Pos, char) -> Result<T>,
+    ) -> Result<Grid<T>> {
+        let Some(first) = lines.first() else {
+            bail!("parsing: empty grid");
+        };
+        let width = first.as_ref().chars().count();
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (row, line) in lines.iter().enumerate() {
+            let line = line.as_ref();
+            let mut count = 0;
+            for (col, ch) in line.chars().enumerate() {
+                let value =
+                    cell(Pos::new(row, col), ch).map_err(|err| match err.is::<ParseError>() {
+                        true => err,
+                        false => ParseError::new(format!("{:#}", err), row, line)
+                            .at(col)
+                            .into(),
+                    });
+                cells.push(value?);
+                count += 1;
+            }
+            if count != width {
+                let message = format!("parsing: expected {} columns, found {}", width, count);
+                bail!(ParseError::new(message, row, line));
+            }
+        }
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        match self.contains(pos) {
+            true => Some(&self.cells[pos.row * self.width + pos.col]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.row * self.width + pos.col]),
+            false => None,
+        }
+    }
+
+    // Every position in the grid, a row at a time.
+    pub fn positions(&self) -> impl DoubleEndedIterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Pos::new(index / width, index % width))
+    }
+
+    // Every cell in the grid along with its position, a row at a time.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // Zero-width grids have no cells to chunk, but still have rows.
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        (0..self.height).map(move |row| &self[Pos::new(row, col)])
+    }
+
+    // The position one |step| away from |pos|, if it's still in the grid.
+    pub fn step(&self, pos: Pos, (rows, cols): Step) -> Option<Pos> {
+        let next = Pos::new(
+            pos.row.checked_add_signed(rows)?,
+            pos.col.checked_add_signed(cols)?,
+        );
+        self.contains(next).then_some(next)
+    }
+
+    // The positions reached by repeatedly taking |step| from |from|, up to the edge of the grid.
+    pub fn ray(&self, from: Pos, step: Step) -> impl Iterator<Item = Pos> + '_ {
+        successors(self.step(from, step), move |&pos| self.step(pos, step))
+    }
+
+    // The positions sharing an edge with |pos|.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    // The positions sharing an edge or a corner with |pos|.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // The grid turned a quarter turn clockwise, so that the left column becomes the top row.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |pos| {
+            self[Pos::new(self.height - 1 - pos.col, pos.row)].clone()
+        })
+    }
+
+    // The grid turned a quarter turn anticlockwise, so that the top row becomes the left column.
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |pos| {
+            self[Pos::new(pos.col, self.width - 1 - pos.row)].clone()
+        })
+    }
+
+    // The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |pos| {
+            self[Pos::new(pos.row, self.width - 1 - pos.col)].clone()
+        })
+    }
+
+    // The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |pos| {
+            self[Pos::new(self.height - 1 - pos.row, pos.col)].clone()
+        })
+    }
+
+    // The grid mirrored along its leading diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |pos| {
+            self[Pos::new(pos.col, pos.row)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside of a {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside of a {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
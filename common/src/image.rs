@@ -0,0 +1,176 @@
+// Pictures of puzzle states, for putting in write-ups. The cells of a grid are turned into pixels
+// by a colour mapping the caller gives, and the picture is saved as a PPM, or as a PNG when the
+// `png` feature is enabled.
+
+use crate::grid::{Grid, Pos};
+use crate::Variant;
+use anyhow::{bail, Context, Result};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+// The colour |num| / |den| of the way from |from| to |to|.
+pub fn blend(from: Rgb, to: Rgb, num: usize, den: usize) -> Rgb {
+    let num = num.min(den);
+    let mut mixed = from;
+    for (channel, to) in mixed.iter_mut().zip(to) {
+        let (from, to) = (*channel as usize, to as usize);
+        *channel = match den {
+            0 => to,
+            _ => (from * (den - num) + to * num) / den,
+        } as u8;
+    }
+    mixed
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    pub pixels: Grid<Rgb>,
+}
+
+impl Image {
+    // One pixel per cell of |grid|, coloured by |colour|.
+    pub fn from_grid<T>(grid: &Grid<T>, mut colour: impl FnMut(Pos, &T) -> Rgb) -> Image {
+        Image {
+            pixels: Grid::from_fn(grid.width(), grid.height(), |pos| colour(pos, &grid[pos])),
+        }
+    }
+
+    // One pixel per cell of |rows|, which must all be the same length.
+    pub fn from_rows<T, R: AsRef<[T]>>(
+        rows: &[R],
+        mut colour: impl FnMut(Pos, &T) -> Rgb,
+    ) -> Result<Image> {
+        let width = rows.first().map_or(0, |row| row.as_ref().len());
+        if let Some(row) = rows.iter().position(|row| row.as_ref().len() != width) {
+            bail!(
+                "image: row {} has {} cells, but the first has {}",
+                row + 1,
+                rows[row].as_ref().len(),
+                width
+            );
+        }
+        Ok(Image {
+            pixels: Grid::from_fn(width, rows.len(), |pos| {
+                colour(pos, &rows[pos.row].as_ref()[pos.col])
+            }),
+        })
+    }
+
+    // One pixel per cell of |cells|, which are laid out row after row, each |width| cells long.
+    pub fn from_flat<T>(
+        cells: &[T],
+        width: usize,
+        mut colour: impl FnMut(Pos, &T) -> Rgb,
+    ) -> Result<Image> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            bail!("image: {} cells don't make rows of {}", cells.len(), width);
+        }
+        Ok(Image {
+            pixels: Grid::from_fn(width, cells.len() / width, |pos| {
+                colour(pos, &cells[pos.row * width + pos.col])
+            }),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    // The same picture with each pixel blown up into a |factor| by |factor| square, as puzzle grids
+    // are too small to make out otherwise.
+    pub fn scaled(&self, factor: usize) -> Image {
+        Image {
+            pixels: Grid::from_fn(self.width() * factor, self.height() * factor, |pos| {
+                self.pixels[Pos::new(pos.row / factor, pos.col / factor)]
+            }),
+        }
+    }
+
+    // Write a binary PPM, which needs nothing more than a header in front of the pixels.
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width(), self.height())?;
+        out.write_all(&self.bytes())
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, out: impl Write) -> Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width() as u32, self.height() as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.bytes())?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    // Save to |path|, in the format its extension names.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        let create = || -> Result<BufWriter<File>> {
+            let file = File::create(path)
+                .with_context(|| format!("could not create {}", path.display()))?;
+            Ok(BufWriter::new(file))
+        };
+        match extension {
+            Some("ppm") => {
+                let mut out = create()?;
+                self.write_ppm(&mut out)?;
+                out.flush()?;
+            }
+            #[cfg(feature = "png")]
+            Some("png") => self.write_png(create()?)?,
+            #[cfg(not(feature = "png"))]
+            Some("png") => bail!("saving a PNG needs the `png` feature of `common`, try a .ppm"),
+            _ => bail!("can't tell what format to save {} in", path.display()),
+        }
+        Ok(())
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|(_, pixel)| *pixel).collect()
+    }
+}
+
+// Take the `--image <path>` flag out of |args|, leaving the rest for |InputArgs|.
+pub fn extract_image_path(
+    args: impl Iterator<Item = String>,
+) -> Result<(Option<PathBuf>, Vec<String>)> {
+    let mut path = None;
+    let mut rest = Vec::new();
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--image" => {
+                path = Some(PathBuf::from(
+                    args.next().context("--image requires a path")?,
+                ))
+            }
+            _ => rest.push(arg),
+        }
+    }
+    Ok((path, rest))
+}
+
+// Where the picture of |variant| goes when both variants are pictured: |path| with the variant
+// added to the end of its name, so `out.png` becomes `out-a.png`.
+pub fn variant_path(path: &Path, variant: Variant) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("");
+    let mut name = format!("{}-{}", stem, variant);
+    if let Some(extension) = path.extension().and_then(|ext| ext.to_str()) {
+        name = format!("{}.{}", name, extension);
+    }
+    path.with_file_name(name)
+}
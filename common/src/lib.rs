@@ -0,0 +1,188 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+
+mod blocks;
+pub mod cycle;
+pub mod gen;
+pub mod grid;
+pub mod image;
+mod normalize;
+mod output;
+mod parse_error;
+pub mod parser;
+pub mod search;
+mod solver;
+pub mod viz;
+
+pub use blocks::{blocks, Block, Blocks};
+pub use normalize::Normalize;
+pub use output::{json_line, parse_json_line, Format, Puzzle};
+pub use parse_error::{LineContext, ParseError};
+pub use solver::{print_answers, run_solver, Answer, DynSolver, Solved, Solver, Unsolved};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Variant {
+    A,
+    B,
+}
+
+impl Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Variant::A => write!(f, "a"),
+            Variant::B => write!(f, "b"),
+        }
+    }
+}
+
+impl Variant {
+    pub const ALL: [Variant; 2] = [Variant::A, Variant::B];
+
+    pub fn parse(text: &str) -> Result<Variant> {
+        match text {
+            "a" | "A" => Ok(Variant::A),
+            "b" | "B" => Ok(Variant::B),
+            _ => bail!("incorrect variant"),
+        }
+    }
+}
+
+// Which of a day's variants an invocation should solve.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Selection {
+    One(Variant),
+    Both,
+}
+
+impl Selection {
+    pub fn parse(text: &str) -> Result<Selection> {
+        match text {
+            "both" => Ok(Selection::Both),
+            _ => Ok(Selection::One(Variant::parse(text)?)),
+        }
+    }
+
+    pub fn variants(&self) -> &'static [Variant] {
+        match self {
+            Selection::One(Variant::A) => &[Variant::A],
+            Selection::One(Variant::B) => &[Variant::B],
+            Selection::Both => &Variant::ALL,
+        }
+    }
+}
+
+// The name of the file, kept in each day's directory, that holds that day's puzzle input.
+pub const DEFAULT_INPUT_FILE: &str = "input.txt";
+
+// Where a binary should read its puzzle input from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl Input {
+    // Interpret a path given on the command line, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Input {
+        match arg {
+            "-" => Input::Stdin,
+            _ => Input::File(PathBuf::from(arg)),
+        }
+    }
+
+    pub fn read_lines(&self) -> Result<Vec<String>> {
+        match self {
+            Input::Stdin => read_lines(io::stdin().lock()).context("could not read stdin"),
+            Input::File(path) => read_input_file_lines(path),
+        }
+    }
+}
+
+const INPUT_USAGE: &str =
+    "usage: <binary> [<path> | --input <path>] [<a|b|both> | --part <a|b|both>]
+    [--format <text|json>]
+    the path defaults to the day's own input.txt, and `-` reads from stdin";
+
+// The input and variants a day's binary was asked to solve, and how to print the answers. The
+// input and variants can be given positionally, as `<path> <part>`, or with the `--input` and
+// `--part` flags. A lone positional argument that names a part (like `a` or `both`) is taken as the
+// part rather than as a path.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InputArgs {
+    pub input: Input,
+    pub selection: Option<Selection>,
+    pub format: Format,
+}
+
+impl InputArgs {
+    // Parse the |args| that follow the binary's name. Without a path, the input is read from the
+    // `input.txt` inside |day_dir|.
+    pub fn parse(mut args: impl Iterator<Item = String>, day_dir: &Path) -> Result<InputArgs> {
+        let mut input = None;
+        let mut selection = None;
+        let mut format = Format::Text;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => {
+                    let value = args.next().context("--input requires a value")?;
+                    input = Some(Input::from_arg(&value));
+                }
+                "--part" => {
+                    let value = args.next().context("--part requires a value")?;
+                    selection = Some(Selection::parse(&value)?);
+                }
+                "--format" => {
+                    let value = args.next().context("--format requires a value")?;
+                    format = Format::parse(&value)?;
+                }
+                _ if arg.starts_with("--") => bail!("unknown flag `{}`\n{}", arg, INPUT_USAGE),
+                _ => match (Selection::parse(&arg), &input, &selection) {
+                    (Ok(parsed), _, None) => selection = Some(parsed),
+                    (_, None, _) => input = Some(Input::from_arg(&arg)),
+                    (Err(err), Some(_), None) => return Err(err),
+                    _ => bail!("too many arguments\n{}", INPUT_USAGE),
+                },
+            }
+        }
+        Ok(InputArgs {
+            input: input.unwrap_or_else(|| Input::File(day_dir.join(DEFAULT_INPUT_FILE))),
+            selection,
+            format,
+        })
+    }
+
+    // Parse the arguments passed to this binary.
+    pub fn from_env(day_dir: &Path) -> Result<InputArgs> {
+        InputArgs::parse(std::env::args().skip(1), day_dir)
+    }
+}
+
+// Read every line of the file at |file_path|, for callers that already know where their input is.
+pub fn read_input_file_lines(file_path: &Path) -> Result<Vec<String>> {
+    let input = File::open(file_path)
+        .with_context(|| format!("could not find input file: {}", file_path.display()))?;
+    read_lines(input)
+}
+
+// Read every line from an already opened |input|.
+pub fn read_lines(input: impl io::Read) -> Result<Vec<String>> {
+    let lines: Result<Vec<_>, _> = io::BufReader::new(input).lines().collect();
+    match lines {
+        Ok(lines) => Ok(lines),
+        Err(err) => Err(err.into()),
+    }
+}
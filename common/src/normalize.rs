@@ -0,0 +1,36 @@
+// Tidying of a day's input lines before they're parsed, so that an input saved by an editor that
+// adds a byte order mark, carriage returns or extra blank lines at the end reads the same as the
+// original. Days opt in through |Solver::normalize|, as some inputs need every character kept.
+
+const BOM: char = '\u{feff}';
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Normalize {
+    // Also strip whitespace from the end of every line. Off by default, as it matters in some
+    // inputs, like the drawing of the stacks in 2022 day 5.
+    pub trim_trailing_whitespace: bool,
+}
+
+impl Normalize {
+    // Strip a byte order mark from the start of |lines| and any carriage returns from the end of
+    // each, then drop the blank lines at the end. Lines are only ever removed from the end, so the
+    // rest keep their line numbers for |ParseError|s.
+    pub fn apply(&self, mut lines: Vec<String>) -> Vec<String> {
+        if let Some(first) = lines.first_mut() {
+            if first.starts_with(BOM) {
+                first.drain(..BOM.len_utf8());
+            }
+        }
+        for line in &mut lines {
+            let end = match self.trim_trailing_whitespace {
+                true => line.trim_end().len(),
+                false => line.trim_end_matches('\r').len(),
+            };
+            line.truncate(end);
+        }
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        lines
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{Answer, Variant};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::path::Path;
+use std::time::Duration;
+
+// How answers should be printed: as plain text for people, or as JSON for tooling.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl Format {
+    pub fn parse(text: &str) -> Result<Format> {
+        match text {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("unknown format `{}`, expected text or json", text),
+        }
+    }
+}
+
+// The puzzle a day's crate solves. Each crate lives at `<year>/day-<N>`, so this is read back
+// from its directory rather than being repeated in every day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+impl Puzzle {
+    pub fn from_day_dir(day_dir: &Path) -> Result<Puzzle> {
+        let day_dir = day_dir
+            .canonicalize()
+            .with_context(|| format!("could not find {}", day_dir.display()))?;
+        let name = |path: Option<&Path>| {
+            path.and_then(Path::file_name)
+                .and_then(|name| name.to_str())
+                .map(str::to_string)
+                .with_context(|| format!("{} is not a <year>/day-<N> directory", day_dir.display()))
+        };
+        let day = name(Some(&day_dir))?;
+        let year = name(day_dir.parent())?;
+        Ok(Puzzle {
+            year: year
+                .parse()
+                .with_context(|| format!("invalid year `{}`", year))?,
+            day: day
+                .strip_prefix("day-")
+                .and_then(|day| day.parse().ok())
+                .with_context(|| format!("invalid day directory `{}`", day))?,
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: Variant,
+    answer: &'a Answer,
+    elapsed_ms: f64,
+}
+
+#[derive(Deserialize)]
+struct OwnedRecord {
+    year: u16,
+    day: u8,
+    part: Variant,
+    answer: Answer,
+    elapsed_ms: f64,
+}
+
+// Describe one solved variant as a single line of JSON. Renders become an array of their rows.
+pub fn json_line(puzzle: Puzzle, variant: Variant, answer: &Answer, elapsed: Duration) -> String {
+    let record = Record {
+        year: puzzle.year,
+        day: puzzle.day,
+        part: variant,
+        answer,
+        elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+    };
+    // Serializing plain numbers, strings and arrays of strings can't fail.
+    serde_json::to_string(&record).unwrap()
+}
+
+// Read back a line written by |json_line|, for tools that run a day's binary and check its answers.
+pub fn parse_json_line(line: &str) -> Result<(Puzzle, Variant, Answer, Duration)> {
+    let record: OwnedRecord = serde_json::from_str(line).context("not an answer line")?;
+    Ok((
+        Puzzle {
+            year: record.year,
+            day: record.day,
+        },
+        record.part,
+        record.answer,
+        Duration::from_secs_f64(record.elapsed_ms.max(0.0) / 1000.0),
+    ))
+}
@@ -0,0 +1,126 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+// Lines longer than this are cut down to a window around the offending column, so that a bad
+// character in a single very long line (day 6, say) still fits on the screen.
+const MAX_SNIPPET: usize = 80;
+
+// A problem found while parsing a puzzle input, pinned to the place in the input where it was
+// found. It renders the way a compiler shows errors:
+//
+//   parsing: invalid character
+//    --> line 3, column 6
+//     |
+//   3 | [1,[2x]]
+//     |      ^
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    // 1-based, as an editor would show it.
+    pub line: usize,
+    // The 1-based column of the first offending character, or None if the whole line is to blame.
+    pub column: Option<usize>,
+    // How many characters from |column| are to blame.
+    pub width: usize,
+    pub snippet: String,
+}
+
+impl ParseError {
+    // Blame the whole of the line at |index| (0-based) in the input, whose text is |snippet|.
+    pub fn new(message: impl Display, index: usize, snippet: &str) -> ParseError {
+        ParseError {
+            message: message.to_string(),
+            line: index + 1,
+            column: None,
+            width: snippet.chars().count(),
+            snippet: snippet.to_string(),
+        }
+    }
+
+    // Narrow the blame down to the single character at |index| (0-based, in chars) in the line.
+    pub fn at(self, index: usize) -> ParseError {
+        ParseError {
+            column: Some(index + 1),
+            width: 1,
+            ..self
+        }
+    }
+
+    // Widen the blame to |width| characters, starting from the column given to |at|.
+    pub fn spanning(self, width: usize) -> ParseError {
+        ParseError {
+            width: width.max(1),
+            ..self
+        }
+    }
+
+    // The part of |snippet| to show, and how far into it the carets start.
+    fn window(&self) -> (String, usize) {
+        let start = self.column.map_or(0, |column| column - 1);
+        let length = self.snippet.chars().count();
+        if length <= MAX_SNIPPET {
+            return (self.snippet.clone(), start);
+        }
+
+        let skip = start
+            .saturating_sub(MAX_SNIPPET / 2)
+            .min(length - MAX_SNIPPET);
+        let mut shown = String::new();
+        if skip > 0 {
+            shown.push_str("...");
+        }
+        shown.extend(self.snippet.chars().skip(skip).take(MAX_SNIPPET));
+        if skip + MAX_SNIPPET < length {
+            shown.push_str("...");
+        }
+        let offset = if skip > 0 { start - skip + 3 } else { start };
+        (shown, offset)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{}", self.message)?;
+        match self.column {
+            Some(column) => writeln!(f, "{}--> line {}, column {}", gutter, self.line, column)?,
+            None => writeln!(f, "{}--> line {}", gutter, self.line)?,
+        }
+        writeln!(f, "{} |", gutter)?;
+
+        let (shown, offset) = self.window();
+        writeln!(f, "{} | {}", self.line, shown)?;
+        let carets = self.width.clamp(1, MAX_SNIPPET);
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(offset),
+            "^".repeat(carets)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// Attaches the line an error was found on, for parsers that work a line at a time. Errors that
+// already carry a position are passed through untouched, so a parser can pinpoint a column where
+// it knows one and leave the rest to this.
+pub trait LineContext<T> {
+    fn line_context(self, index: usize, line: &str) -> anyhow::Result<T>;
+}
+
+impl<T, E> LineContext<T> for Result<T, E>
+where
+    E: Into<anyhow::Error>,
+{
+    fn line_context(self, index: usize, line: &str) -> anyhow::Result<T> {
+        self.map_err(|err| {
+            let err = err.into();
+            if err.is::<ParseError>() {
+                return err;
+            }
+            ParseError::new(format!("{:#}", err), index, line).into()
+        })
+    }
+}
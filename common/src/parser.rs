@@ -0,0 +1,229 @@
+// A small toolkit for the line-oriented formats puzzle inputs come in. A |Cursor| walks through
+// a single line, and each of its methods consumes one piece of syntax (an integer, a literal, a
+// list, ...) or fails with a |ParseError| pointing at where it went wrong. Spaces between pieces
+// are skipped, so `498,4 -> 498,6` and `498,4->498,6` parse the same way. For example:
+//
+//   let path = parser::line(index, &line, |c| {
+//       c.separated("->", |c| {
+//           let col = c.integer()?;
+//           c.literal(",")?;
+//           Ok((col, c.integer()?))
+//       })
+//   })?;
+//
+// Inputs made of blank-line-separated records are handled a |Record| at a time instead, with
+// |Record::field| for the `Key: value` lines such records tend to be made of.
+
+use crate::{Block, ParseError};
+use std::str::FromStr;
+
+pub type PResult<T> = Result<T, ParseError>;
+
+// A position within the line at |index| in the input.
+pub struct Cursor<'a> {
+    index: usize,
+    line: &'a str,
+    // A byte offset into |line|.
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(index: usize, line: &'a str) -> Cursor<'a> {
+        Cursor {
+            index,
+            line,
+            pos: 0,
+        }
+    }
+
+    // The part of the line that's yet to be consumed.
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.pos..]
+    }
+
+    pub fn is_done(&mut self) -> bool {
+        self.skip_spaces();
+        self.rest().is_empty()
+    }
+
+    // An error blaming the character at the cursor.
+    pub fn error(&self, message: &str) -> ParseError {
+        self.error_at(self.pos, message)
+    }
+
+    fn error_at(&self, pos: usize, message: &str) -> ParseError {
+        let column = self.line[..pos].chars().count();
+        ParseError::new(format!("parsing: {}", message), self.index, self.line).at(column)
+    }
+
+    fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    // Consume |text| if it comes next, reporting whether it did.
+    pub fn eat(&mut self, text: &str) -> bool {
+        self.skip_spaces();
+        if self.rest().starts_with(text) {
+            self.pos += text.len();
+            return true;
+        }
+        false
+    }
+
+    pub fn literal(&mut self, text: &str) -> PResult<()> {
+        match self.eat(text) {
+            true => Ok(()),
+            false => Err(self.error(&format!("expected `{}`", text))),
+        }
+    }
+
+    // Consume whichever of the |choices| comes next, and return the value paired with it.
+    pub fn one_of<T: Clone>(&mut self, choices: &[(&str, T)]) -> PResult<T> {
+        for (text, value) in choices {
+            if self.eat(text) {
+                return Ok(value.clone());
+            }
+        }
+        let names = choices
+            .iter()
+            .map(|(text, _)| format!("`{}`", text))
+            .collect::<Vec<_>>();
+        Err(self.error(&format!("expected one of {}", names.join(", "))))
+    }
+
+    // A run of anything other than whitespace.
+    pub fn word(&mut self) -> PResult<&'a str> {
+        self.skip_spaces();
+        let rest = self.rest();
+        let word = &rest[..rest.find(char::is_whitespace).unwrap_or(rest.len())];
+        if word.is_empty() {
+            return Err(self.error("expected a word"));
+        }
+        self.pos += word.len();
+        Ok(word)
+    }
+
+    // A decimal integer, with an optional sign, that must fit in a |T|.
+    pub fn integer<T: FromStr>(&mut self) -> PResult<T> {
+        self.skip_spaces();
+        let start = self.pos;
+        let rest = self.rest();
+        let sign = match rest.starts_with(['-', '+']) {
+            true => 1,
+            false => 0,
+        };
+        let digits = rest[sign..]
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if digits == 0 {
+            return Err(self.error("expected an integer"));
+        }
+
+        let text = &rest[..sign + digits];
+        let value = text.parse::<T>().map_err(|_| {
+            self.error_at(start, &format!("`{}` is out of range", text))
+                .spanning(text.len())
+        })?;
+        self.pos += text.len();
+        Ok(value)
+    }
+
+    // One or more |item|s, separated by |separator|.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Cursor<'a>) -> PResult<T>,
+    ) -> PResult<Vec<T>> {
+        let mut items = vec![item(self)?];
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    // Fail unless the whole line has been consumed.
+    pub fn end(&mut self) -> PResult<()> {
+        self.skip_spaces();
+        let rest = self.rest();
+        match rest.is_empty() {
+            true => Ok(()),
+            false => Err(self
+                .error("unexpected trailing text")
+                .spanning(rest.chars().count())),
+        }
+    }
+}
+
+// Parse the whole of the line at |index| in the input with |parser|.
+pub fn line<'a, T>(
+    index: usize,
+    line: &'a str,
+    parser: impl FnOnce(&mut Cursor<'a>) -> PResult<T>,
+) -> PResult<T> {
+    let mut cursor = Cursor::new(index, line);
+    let value = parser(&mut cursor)?;
+    cursor.end()?;
+    Ok(value)
+}
+
+// A |Block| of the input holding a single record, that's parsed a line at a time.
+pub struct Record<'a> {
+    start: usize,
+    lines: Vec<&'a str>,
+    next: usize,
+}
+
+impl<'a> Record<'a> {
+    pub fn new<T: AsRef<str>>(block: &'a Block<T>) -> Record<'a> {
+        Record {
+            start: block.start,
+            lines: block.lines.iter().map(AsRef::as_ref).collect(),
+            next: 0,
+        }
+    }
+
+    // Parse the next line of the record with |parser|.
+    pub fn line<T>(&mut self, parser: impl FnOnce(&mut Cursor<'a>) -> PResult<T>) -> PResult<T> {
+        let index = self.start + self.next;
+        match self.lines.get(self.next) {
+            Some(text) => {
+                self.next += 1;
+                line(index, text, parser)
+            }
+            None => {
+                // Blame the end of the last line, since that's where more was expected.
+                let last = self.lines.last().copied().unwrap_or_default();
+                Err(
+                    ParseError::new("parsing: record ended early", index.saturating_sub(1), last)
+                        .at(last.chars().count()),
+                )
+            }
+        }
+    }
+
+    // Parse the next line of the record as `key: value`, with |parser| handling the value.
+    pub fn field<T>(
+        &mut self,
+        key: &str,
+        parser: impl FnOnce(&mut Cursor<'a>) -> PResult<T>,
+    ) -> PResult<T> {
+        self.line(|c| {
+            c.literal(key)?;
+            c.literal(":")?;
+            parser(c)
+        })
+    }
+
+    // Fail unless every line of the record has been parsed.
+    pub fn end(&self) -> PResult<()> {
+        match self.lines.get(self.next) {
+            None => Ok(()),
+            Some(text) => Err(ParseError::new(
+                "parsing: unexpected line at the end of the record",
+                self.start + self.next,
+                text,
+            )),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+// Graph searches over nodes given implicitly by a successor function, so they work just as well
+// over the cells of a |Grid| as over states of a simulation. Each search can start from several
+// nodes at once, stops at the first node that satisfies |is_goal| (pass `|_| false` to explore
+// everything reachable), and returns the |Paths| it found along the way.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// What a path can cost, with |Default| as zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+// The cheapest paths a search found to each node it reached.
+pub struct Paths<N, C> {
+    costs: HashMap<N, C>,
+    // The node each reached node was reached from. Starts have no entry.
+    came_from: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Paths<N, C> {
+    fn new() -> Paths<N, C> {
+        Paths {
+            costs: HashMap::new(),
+            came_from: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    // The goal the search stopped at, if it found one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    // The cost of the cheapest path found to |node|, if it was reached.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    // Every node reached, along with the cost of reaching it.
+    pub fn reached(&self) -> impl Iterator<Item = (&N, C)> {
+        self.costs.iter().map(|(node, cost)| (node, *cost))
+    }
+
+    // The cheapest path found to |node|, from whichever start it began at up to and including
+    // |node| itself.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.came_from.get(path.last()?) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    // The cheapest path found to the goal.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+}
+
+// Breadth-first search, where each step costs 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !paths.costs.contains_key(&start) {
+            paths.costs.insert(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, cost)) = queue.pop_front() {
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        for next in successors(&node) {
+            // The first visit to a node is always along a cheapest path.
+            if !paths.costs.contains_key(&next) {
+                paths.costs.insert(next.clone(), cost + 1);
+                paths.came_from.insert(next.clone(), node.clone());
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    paths
+}
+
+// Dijkstra's algorithm, for when steps cost different amounts. Costs mustn't be negative.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+// A*, which heads towards the goal first by adding the |heuristic| estimate of the cost left to
+// get there from each node. For the paths found to be the cheapest, the estimate must never be
+// more than the real cost, nor drop by more than the cost of a step when that step is taken.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if !paths.costs.contains_key(&start) {
+            paths.costs.insert(start.clone(), C::default());
+            queue.push(Entry {
+                estimate: heuristic(&start),
+                cost: C::default(),
+                node: start,
+            });
+        }
+    }
+
+    while let Some(Entry { cost, node, .. }) = queue.pop() {
+        // Nodes are queued again whenever a cheaper path to them is found, so skip the stale
+        // entries left behind.
+        if paths.costs.get(&node).is_some_and(|best| *best < cost) {
+            continue;
+        }
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if paths.costs.get(&next).is_none_or(|best| next_cost < *best) {
+                paths.costs.insert(next.clone(), next_cost);
+                paths.came_from.insert(next.clone(), node.clone());
+                queue.push(Entry {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    paths
+}
+
+// A node waiting in the |astar| queue. They're ordered so that the max-heap |BinaryHeap| pops the
+// one with the lowest estimated total cost first.
+struct Entry<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Between equal estimates, prefer the node further along its path.
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
@@ -0,0 +1,301 @@
+use crate::image::{extract_image_path, variant_path, Image};
+use crate::viz::{Playback, Scene, Terminal};
+use crate::{json_line, Format, Input, InputArgs, Normalize, Puzzle, Selection, Variant};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// The result of solving one variant of a day's puzzle.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+    // A multi-line picture that has to be read by eye, like a CRT display.
+    Render(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(num) => write!(f, "{}", num),
+            Answer::Str(text) => write!(f, "{}", text),
+            Answer::Render(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(num: $int) -> Answer {
+                    Answer::Int(num as i64)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Str(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Str(text.to_string())
+    }
+}
+
+// The error a part gives until it's been solved, so that a run over many days can tell a part
+// that's still to be done from one that's broken.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Unsolved(pub Variant);
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part {} has not been solved yet", self.0)
+    }
+}
+
+impl std::error::Error for Unsolved {}
+
+// A single day's puzzle. The input is parsed once into |Parsed|, which both variants then solve
+// from without printing anything themselves.
+pub trait Solver {
+    type Parsed;
+
+    fn parse(&self, lines: Vec<String>) -> Result<Self::Parsed>;
+    fn part_a(&self, parsed: &Self::Parsed) -> Result<Answer>;
+    fn part_b(&self, parsed: &Self::Parsed) -> Result<Answer>;
+
+    // How to tidy the input's lines before they're parsed. They're passed on untouched unless a day
+    // opts in.
+    fn normalize(&self) -> Option<Normalize> {
+        None
+    }
+
+    // A step-by-step playback of solving |variant|, for days whose puzzles are simulations.
+    fn scene<'a>(
+        &self,
+        _parsed: &'a Self::Parsed,
+        _variant: Variant,
+    ) -> Result<Box<dyn Scene + 'a>> {
+        bail!("this day has no animation")
+    }
+
+    // A picture of the puzzle's state once |variant| is solved.
+    fn picture(&self, _parsed: &Self::Parsed, _variant: Variant) -> Result<Image> {
+        bail!("this day has no picture")
+    }
+}
+
+// One variant's answer, or why it couldn't be found, and how long solving it took (not counting
+// reading or parsing the input).
+pub struct Solved {
+    pub variant: Variant,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+// An object-safe view of a |Solver|, so that days with different |Parsed| types can be stored and
+// called side by side, from any thread. Parsing and solving are exposed separately so that callers
+// can time them, or solve several variants from a single parse.
+pub trait DynSolver: Sync {
+    fn parse_input(&self, lines: Vec<String>) -> Result<Box<dyn Any>>;
+    fn solve_parsed(&self, parsed: &dyn Any, variant: Variant) -> Result<Answer>;
+    fn scene_parsed<'a>(
+        &self,
+        parsed: &'a dyn Any,
+        variant: Variant,
+    ) -> Result<Box<dyn Scene + 'a>>;
+    fn picture_parsed(&self, parsed: &dyn Any, variant: Variant) -> Result<Image>;
+
+    fn solve(&self, lines: Vec<String>, variant: Variant) -> Result<Answer> {
+        let parsed = self.parse_input(lines)?;
+        self.solve_parsed(parsed.as_ref(), variant)
+    }
+
+    // Parse the |lines| once, and solve each of the |variants| from that single parse. A variant
+    // that fails doesn't stop the rest from being solved.
+    fn solve_each(&self, lines: Vec<String>, variants: &[Variant]) -> Result<Vec<Solved>> {
+        let parsed = self.parse_input(lines)?;
+        Ok(variants
+            .iter()
+            .map(|variant| {
+                let start = Instant::now();
+                let answer = self.solve_parsed(parsed.as_ref(), *variant);
+                Solved {
+                    variant: *variant,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect())
+    }
+}
+
+impl<S: Solver + Sync> DynSolver for S
+where
+    S::Parsed: 'static,
+{
+    fn parse_input(&self, lines: Vec<String>) -> Result<Box<dyn Any>> {
+        let lines = match self.normalize() {
+            Some(normalize) => normalize.apply(lines),
+            None => lines,
+        };
+        Ok(Box::new(self.parse(lines)?))
+    }
+
+    fn solve_parsed(&self, parsed: &dyn Any, variant: Variant) -> Result<Answer> {
+        let parsed = downcast::<S>(parsed)?;
+        match variant {
+            Variant::A => self.part_a(parsed),
+            Variant::B => self.part_b(parsed),
+        }
+    }
+
+    fn scene_parsed<'a>(
+        &self,
+        parsed: &'a dyn Any,
+        variant: Variant,
+    ) -> Result<Box<dyn Scene + 'a>> {
+        self.scene(downcast::<S>(parsed)?, variant)
+    }
+
+    fn picture_parsed(&self, parsed: &dyn Any, variant: Variant) -> Result<Image> {
+        self.picture(downcast::<S>(parsed)?, variant)
+    }
+}
+
+fn downcast<S: Solver>(parsed: &dyn Any) -> Result<&S::Parsed>
+where
+    S::Parsed: 'static,
+{
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .context("parsed input is for a different solver")
+}
+
+// Load the input and variants from the binary's arguments, and print the |solver|'s answers. The
+// input defaults to the `input.txt` inside |day_dir|, and both variants are solved unless one is
+// picked. Before the answers are printed, each variant can be played back with the animation
+// flags, and pictured with `--image <path>`.
+pub fn run_solver(solver: &dyn DynSolver, day_dir: impl AsRef<Path>) -> Result<()> {
+    let day_dir = day_dir.as_ref();
+    let (playback, rest) = Playback::extract(std::env::args().skip(1))?;
+    let (image, rest) = extract_image_path(rest.into_iter())?;
+    let args = InputArgs::parse(rest.into_iter(), day_dir)?;
+    let puzzle = Puzzle::from_day_dir(day_dir)?;
+    if playback.is_none() && image.is_none() {
+        return print_answers(solver, puzzle, &args, Selection::Both);
+    }
+
+    let selection = args.selection.unwrap_or(Selection::Both);
+    let lines = args.input.read_lines()?;
+    let parsed = solver.parse_input(lines.clone())?;
+    if let Some(playback) = playback {
+        // Keys are read from stdin, so the input can't come from there too.
+        if args.input == Input::Stdin {
+            bail!("can't animate input read from stdin");
+        }
+        let terminal = Terminal::open()?;
+        show_each(selection, "animated", |variant| {
+            let mut scene = solver.scene_parsed(parsed.as_ref(), variant)?;
+            terminal.play(scene.as_mut(), playback)?;
+            Ok(())
+        })?;
+    }
+    if let Some(path) = image {
+        show_each(selection, "pictured", |variant| {
+            let image = solver.picture_parsed(parsed.as_ref(), variant)?;
+            match selection {
+                Selection::One(_) => image.save(&path),
+                Selection::Both => image.save(&variant_path(&path, variant)),
+            }
+        })?;
+    }
+    print_solved(
+        puzzle,
+        &args,
+        selection,
+        solver.solve_each(lines, selection.variants())?,
+    )
+}
+
+// Show each variant picked by |selection|. Like a failed answer, a variant that can't be shown only
+// stops the others if it was the only one picked.
+fn show_each(
+    selection: Selection,
+    shown: &str,
+    mut show: impl FnMut(Variant) -> Result<()>,
+) -> Result<()> {
+    for variant in selection.variants() {
+        match (show(*variant), selection) {
+            (Ok(()), _) => {}
+            (Err(err), Selection::One(_)) => return Err(err),
+            (Err(err), Selection::Both) => {
+                eprintln!("part {} can't be {}: {:#}", variant, shown, err)
+            }
+        }
+    }
+    Ok(())
+}
+
+// Solve the variants picked by |args|, or the |default| ones, from a single parse of the input. As
+// text, a lone variant's answer is printed bare and both are labelled with their variant; as JSON,
+// each answer is a line of its own.
+pub fn print_answers(
+    solver: &dyn DynSolver,
+    puzzle: Puzzle,
+    args: &InputArgs,
+    default: Selection,
+) -> Result<()> {
+    let selection = args.selection.unwrap_or(default);
+    let solved = solver.solve_each(args.input.read_lines()?, selection.variants())?;
+    print_solved(puzzle, args, selection, solved)
+}
+
+fn print_solved(
+    puzzle: Puzzle,
+    args: &InputArgs,
+    selection: Selection,
+    solved: Vec<Solved>,
+) -> Result<()> {
+    let mut failures = 0;
+    for Solved {
+        variant,
+        answer,
+        elapsed,
+    } in solved
+    {
+        let answer = match (answer, selection) {
+            (Ok(answer), _) => answer,
+            (Err(err), Selection::One(_)) => return Err(err),
+            (Err(err), Selection::Both) => {
+                eprintln!("part {} failed: {:#}", variant, err);
+                failures += 1;
+                continue;
+            }
+        };
+        match (args.format, selection, &answer) {
+            (Format::Json, _, _) => println!("{}", json_line(puzzle, variant, &answer, elapsed)),
+            (Format::Text, Selection::One(_), _) => println!("{}", answer),
+            (Format::Text, Selection::Both, Answer::Render(_)) => {
+                println!("part {}:\n{}", variant, answer)
+            }
+            (Format::Text, Selection::Both, _) => println!("part {}: {}", variant, answer),
+        }
+    }
+    if failures > 0 {
+        bail!("{} part(s) failed", failures);
+    }
+    Ok(())
+}
@@ -0,0 +1,293 @@
+// Frame-by-frame playback of a puzzle's simulation in the terminal. A day describes its simulation
+// as a |Scene|, which is stepped and redrawn in place with ANSI escapes at a steady rate, while
+// keys pressed in the terminal pause, single-step, speed up or stop the playback.
+
+use anyhow::{bail, Context, Result};
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// A simulation that can be watched one step at a time.
+pub trait Scene {
+    // Advance by one step, returning false once there's nothing left to simulate.
+    fn step(&mut self) -> Result<bool>;
+    // Draw the current state, one terminal row per line.
+    fn frame(&self) -> String;
+}
+
+pub const DEFAULT_FPS: u32 = 30;
+pub const MAX_FPS: u32 = 1000;
+
+// How a |Scene| should be played back.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Playback {
+    pub fps: u32,
+    // How many steps to simulate without drawing them between each frame that is drawn.
+    pub skip: usize,
+    // Whether to wait for a key before the first step.
+    pub paused: bool,
+}
+
+impl Default for Playback {
+    fn default() -> Playback {
+        Playback {
+            fps: DEFAULT_FPS,
+            skip: 0,
+            paused: false,
+        }
+    }
+}
+
+const PLAYBACK_USAGE: &str = "animation flags: --animate [--fps <n>] [--skip <n>] [--paused]
+    while playing, space pauses, n steps while paused, + and - change the speed and q stops";
+
+impl Playback {
+    // Take the animation flags out of |args|, leaving the rest for |InputArgs|. Any of them asks
+    // for playback, not just `--animate`.
+    pub fn extract(args: impl Iterator<Item = String>) -> Result<(Option<Playback>, Vec<String>)> {
+        let mut playback = None;
+        let mut rest = Vec::new();
+        let mut args = args;
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| -> Result<usize> {
+                let value = args
+                    .next()
+                    .with_context(|| format!("{} requires a value\n{}", name, PLAYBACK_USAGE))?;
+                value
+                    .parse()
+                    .with_context(|| format!("invalid {} `{}`", name, value))
+            };
+            let options = match arg.as_str() {
+                "--animate" | "--fps" | "--skip" | "--paused" => {
+                    playback.get_or_insert_with(Playback::default)
+                }
+                _ => {
+                    rest.push(arg);
+                    continue;
+                }
+            };
+            match arg.as_str() {
+                "--fps" => match value("--fps")? {
+                    fps if (1..=MAX_FPS as usize).contains(&fps) => options.fps = fps as u32,
+                    fps => bail!("--fps must be between 1 and {}, not {}", MAX_FPS, fps),
+                },
+                "--skip" => options.skip = value("--skip")?,
+                "--paused" => options.paused = true,
+                _ => {}
+            }
+        }
+        Ok((playback, rest))
+    }
+}
+
+// What a key pressed during playback asks for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Key {
+    Pause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Key {
+    pub fn from_byte(byte: u8) -> Option<Key> {
+        match byte {
+            b' ' | b'p' => Some(Key::Pause),
+            b'n' | b'.' => Some(Key::Step),
+            b'+' | b'=' => Some(Key::Faster),
+            b'-' | b'_' => Some(Key::Slower),
+            // Ctrl-C and Esc arrive as bytes too, as the terminal stops handling them itself.
+            b'q' | 0x03 | 0x1b => Some(Key::Quit),
+            _ => None,
+        }
+    }
+}
+
+// How far a playback got.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Played {
+    pub steps: usize,
+    pub frames: usize,
+    // Whether the scene ran to its end, rather than being stopped early.
+    pub finished: bool,
+}
+
+// Play |scene| back to |out|, taking keys from |keys|. Once |keys| is closed, playback carries on
+// without them, and won't stay paused.
+pub fn play(
+    scene: &mut dyn Scene,
+    playback: Playback,
+    keys: &Receiver<Key>,
+    out: &mut impl Write,
+) -> Result<Played> {
+    let mut fps = playback.fps;
+    let mut paused = playback.paused;
+    let mut keys_open = true;
+    let mut played = Played {
+        steps: 0,
+        frames: 0,
+        finished: false,
+    };
+    draw(scene, &mut played, fps, paused, out)?;
+
+    loop {
+        // Wait out the rest of the frame, or until the paused playback is told to move on.
+        let deadline = Instant::now() + Duration::from_secs(1) / fps;
+        let mut step_once = false;
+        while keys_open {
+            let key = match paused {
+                true => keys.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                false => keys.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            };
+            match key {
+                Ok(Key::Pause) => paused = !paused,
+                Ok(Key::Step) if paused => step_once = true,
+                Ok(Key::Step) => {}
+                Ok(Key::Faster) => fps = (fps * 2).min(MAX_FPS),
+                Ok(Key::Slower) => fps = (fps / 2).max(1),
+                Ok(Key::Quit) => return Ok(played),
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => {
+                    keys_open = false;
+                    break;
+                }
+            }
+            if step_once {
+                break;
+            }
+            // Show a change of speed or pause straight away.
+            draw(scene, &mut played, fps, paused, out)?;
+        }
+        if !keys_open {
+            paused = false;
+            thread::sleep(deadline.saturating_duration_since(Instant::now()));
+        }
+
+        // Simulate up to the next frame that gets drawn.
+        for _ in 0..=playback.skip {
+            if !scene.step()? {
+                played.finished = true;
+                break;
+            }
+            played.steps += 1;
+        }
+        draw(scene, &mut played, fps, paused, out)?;
+        if played.finished {
+            return Ok(played);
+        }
+    }
+}
+
+// Redraw |scene| over the previous frame, with a status line underneath.
+fn draw(
+    scene: &dyn Scene,
+    played: &mut Played,
+    fps: u32,
+    paused: bool,
+    out: &mut impl Write,
+) -> Result<()> {
+    let mut text = String::from("\x1b[H");
+    for row in scene.frame().lines() {
+        text.push_str(row);
+        text.push_str("\x1b[K\n");
+    }
+    let state = match (played.finished, paused) {
+        (true, _) => "done",
+        (false, true) => "paused",
+        (false, false) => "playing",
+    };
+    text.push_str(&format!(
+        "step {} at {} fps, {}  [space] pause  [n] step  [+/-] speed  [q] stop\x1b[K\n\x1b[J",
+        played.steps, fps, state
+    ));
+    out.write_all(text.as_bytes())?;
+    out.flush()?;
+    played.frames += 1;
+    Ok(())
+}
+
+// Puts the terminal into a mode where each key press can be read as soon as it's made, without
+// being echoed, and puts it back when dropped.
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> Option<RawMode> {
+        if !io::stdin().is_terminal() {
+            return None;
+        }
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        Some(RawMode {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        stty(&[&self.saved]);
+    }
+}
+
+// Run `stty` on the terminal attached to stdin, returning what it printed if it succeeded.
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+    match output.status.success() {
+        true => String::from_utf8(output.stdout).ok(),
+        false => None,
+    }
+}
+
+// The terminal this process runs in, set up for playback. Keys are only read if stdin is a
+// terminal, and the terminal is put back as it was when this is dropped.
+pub struct Terminal {
+    keys: Receiver<Key>,
+    // Dropped after the cursor is shown again.
+    _raw: Option<RawMode>,
+}
+
+impl Terminal {
+    pub fn open() -> Result<Terminal> {
+        let (sender, keys) = mpsc::channel();
+        let raw = RawMode::enable();
+        if raw.is_some() {
+            // A single reader for the whole run, as a read can't be abandoned once it's started.
+            thread::spawn(move || {
+                for byte in io::stdin().lock().bytes() {
+                    let Ok(byte) = byte else { return };
+                    if let Some(key) = Key::from_byte(byte) {
+                        if sender.send(key).is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+        // Start from a clear screen, with the cursor hidden while frames are drawn.
+        let mut out = io::stdout().lock();
+        write!(out, "\x1b[2J\x1b[?25l")?;
+        out.flush()?;
+        Ok(Terminal { keys, _raw: raw })
+    }
+
+    pub fn play(&self, scene: &mut dyn Scene, playback: Playback) -> Result<Played> {
+        play(scene, playback, &self.keys, &mut io::stdout().lock())
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let mut out = io::stdout().lock();
+        let _ = write!(out, "\x1b[?25h");
+        let _ = out.flush();
+    }
+}
@@ -0,0 +1,33 @@
+use common::{blocks, Block};
+use std::io::{BufRead, Cursor};
+
+#[test]
+fn splits_on_blank_lines() {
+    let input = ["", "a", "b", "", "", "c", ""];
+    let found = blocks(input).collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        [
+            Block {
+                start: 1,
+                lines: vec!["a", "b"]
+            },
+            Block {
+                start: 5,
+                lines: vec!["c"]
+            },
+        ]
+    );
+    assert!(blocks(["", ""]).next().is_none());
+}
+
+#[test]
+fn numbers_lines_while_streaming() {
+    let reader = Cursor::new("1\n2\n\n3\n");
+    let mut found = blocks(reader.lines().map(Result::unwrap));
+    let first = found.next().unwrap();
+    assert_eq!(first.numbered().collect::<Vec<_>>(), [(0, "1"), (1, "2")]);
+    let second = found.next().unwrap();
+    assert_eq!(second.numbered().collect::<Vec<_>>(), [(3, "3")]);
+    assert!(found.next().is_none());
+}
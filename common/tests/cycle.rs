@@ -0,0 +1,91 @@
+use common::cycle::{brent, fast_forward, Cycle, Orbit};
+
+// x -> x² + 1 (mod 255) from 3 runs 3, 10, 101, 2, 5, 26, 167, 95, 101, ... so it enters a cycle
+// of length 6 at step 2.
+fn step(x: &u32) -> u32 {
+    (x * x + 1) % 255
+}
+
+const CYCLE: Cycle = Cycle {
+    start: 2,
+    length: 6,
+};
+
+#[test]
+fn brent_finds_where_the_cycle_starts_and_its_length() {
+    assert_eq!(brent(3, step), CYCLE);
+    assert_eq!(
+        brent(0, |x| (x + 1) % 5),
+        Cycle {
+            start: 0,
+            length: 5
+        }
+    );
+}
+
+#[test]
+fn orbit_agrees_with_brent() {
+    let orbit = Orbit::trace(3, step, 1000);
+    assert_eq!(orbit.cycle, Some(CYCLE));
+    assert_eq!(orbit.states, [3, 10, 101, 2, 5, 26, 167, 95]);
+
+    // Stopping short of a repeat leaves the cycle unknown.
+    let orbit = Orbit::trace(3, step, 5);
+    assert_eq!((orbit.states.len(), orbit.cycle), (5, None));
+    assert_eq!(orbit.nth(4), Some(&5));
+    assert_eq!(orbit.nth(5), None);
+}
+
+#[test]
+fn fast_forward_matches_stepping_every_time() {
+    let mut state = 3;
+    for n in 0..100 {
+        assert_eq!(fast_forward(3, step, n), state, "step {}", n);
+        state = step(&state);
+    }
+    assert_eq!(fast_forward(3, step, 1_000_000_000_000), 5);
+    assert_eq!(
+        fast_forward(3, step, usize::MAX),
+        fast_forward(3, step, CYCLE.equivalent(usize::MAX))
+    );
+}
+
+#[test]
+fn counts_occurrences_over_many_steps() {
+    let orbit = Orbit::trace(3, step, 1000);
+    for n in [0, 1, 2, 7, 8, 9, 100] {
+        let mut naive = 0;
+        let mut state = 3;
+        for _ in 0..n {
+            naive += state;
+            state = step(&state);
+        }
+        let total = orbit
+            .occurrences(n)
+            .map(|(state, count)| *state as usize * count)
+            .sum::<usize>();
+        assert_eq!(total, naive as usize, "first {} steps", n);
+    }
+}
+
+#[test]
+fn try_trace_stops_at_the_first_failure() {
+    let traced = Orbit::try_trace(1u8, |x| x.checked_mul(3).ok_or("overflow"), 100);
+    assert_eq!(traced.unwrap_err(), "overflow");
+}
+
+#[test]
+fn try_trace_never_steps_past_the_limit() {
+    let traced = Orbit::try_trace(
+        0u8,
+        |x| {
+            if *x < 2 {
+                Ok(x + 1)
+            } else {
+                Err("past the limit")
+            }
+        },
+        3,
+    );
+    assert_eq!(traced.unwrap().states, [0, 1, 2]);
+}
@@ -0,0 +1,39 @@
+use common::gen::{generator, Rng};
+use common::Puzzle;
+
+#[test]
+fn repeats_the_same_numbers_from_the_same_seed() {
+    let take = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..8).map(|_| rng.next_u64()).collect::<Vec<_>>()
+    };
+    assert_eq!(take(42), take(42));
+    assert_ne!(take(42), take(43));
+}
+
+#[test]
+fn stays_within_bounds() {
+    let mut rng = Rng::new(1);
+    let mut seen = [false; 4];
+    for _ in 0..200 {
+        let num = rng.range(3..=6);
+        assert!((3..=6).contains(&num));
+        seen[num - 3] = true;
+    }
+    assert_eq!(seen, [true; 4]);
+    assert_eq!(rng.range(9..=9), 9);
+    assert!((0..100).all(|_| !rng.chance(0, 5) && rng.chance(5, 5)));
+
+    let mut items = [1, 2, 3, 4, 5, 6];
+    rng.shuffle(&mut items);
+    items.sort();
+    assert_eq!(items, [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn finds_generators_by_puzzle() {
+    let puzzle = |year, day| Puzzle { year, day };
+    assert!(generator(puzzle(2022, 14)).is_some());
+    assert!(generator(puzzle(2022, 25)).is_none());
+    assert!(generator(puzzle(2015, 1)).is_none());
+}
@@ -0,0 +1,69 @@
+use anyhow::bail;
+use common::grid::{Grid, Pos, RIGHT, UP};
+use common::ParseError;
+
+fn letters(rows: &[&str]) -> Grid<char> {
+    Grid::parse(rows, |_, ch| Ok(ch)).unwrap()
+}
+
+#[test]
+fn parses_and_displays_character_lines() {
+    let grid = letters(&["abc", "def"]);
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Pos::new(1, 2)], 'f');
+    assert_eq!(grid.get(Pos::new(2, 0)), None);
+    assert_eq!(grid.to_string(), "abc\ndef");
+}
+
+#[test]
+fn pins_parse_errors_to_their_cell() {
+    let digits = Grid::parse(&["12", "3x"], |_, ch| match ch.to_digit(10) {
+        Some(digit) => Ok(digit),
+        None => bail!("parsing: not a digit"),
+    });
+    let err = digits.unwrap_err().downcast::<ParseError>().unwrap();
+    assert_eq!((err.line, err.column), (2, Some(2)));
+
+    let ragged = Grid::parse(&["12", "345"], |_, ch| Ok(ch));
+    let err = ragged.unwrap_err().downcast::<ParseError>().unwrap();
+    assert_eq!(err.message, "parsing: expected 2 columns, found 3");
+    assert_eq!((err.line, err.column), (2, None));
+}
+
+#[test]
+fn finds_neighbours_within_bounds() {
+    let grid = letters(&["abc", "def", "ghi"]);
+    let around = |pos, eight: bool| {
+        let found: Vec<Pos> = match eight {
+            true => grid.neighbours8(pos).collect(),
+            false => grid.neighbours4(pos).collect(),
+        };
+        found.into_iter().map(|pos| grid[pos]).collect::<String>()
+    };
+    assert_eq!(around(Pos::new(0, 0), false), "db");
+    assert_eq!(around(Pos::new(1, 1), false), "bhdf");
+    assert_eq!(around(Pos::new(1, 1), true), "abcdfghi");
+    assert_eq!(around(Pos::new(2, 2), true), "efh");
+}
+
+#[test]
+fn walks_rows_columns_and_rays() {
+    let grid = letters(&["abc", "def", "ghi"]);
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.column(2).collect::<String>(), "cfi");
+    let ray = |pos, step| grid.ray(pos, step).map(|pos| grid[pos]).collect::<String>();
+    assert_eq!(ray(Pos::new(2, 0), UP), "da");
+    assert_eq!(ray(Pos::new(1, 0), RIGHT), "ef");
+    assert_eq!(ray(Pos::new(0, 0), UP), "");
+}
+
+#[test]
+fn transforms() {
+    let grid = letters(&["abc", "def"]);
+    assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+}
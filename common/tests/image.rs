@@ -0,0 +1,89 @@
+use common::grid::{Grid, Pos};
+use common::image::{blend, extract_image_path, variant_path, Image, BLACK, WHITE};
+use common::Variant;
+use std::path::Path;
+
+fn checkerboard() -> Image {
+    let grid = Grid::from_fn(3, 2, |pos| (pos.row + pos.col) % 2 == 0);
+    Image::from_grid(&grid, |_, &lit| if lit { WHITE } else { BLACK })
+}
+
+#[test]
+fn writes_a_binary_ppm() {
+    let mut out = Vec::new();
+    checkerboard().write_ppm(&mut out).unwrap();
+    let mut expected = b"P6\n3 2\n255\n".to_vec();
+    for lit in [true, false, true, false, true, false] {
+        expected.extend([if lit { 255 } else { 0 }; 3]);
+    }
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn reads_nested_and_flat_layouts_alike() {
+    let colour = |_: Pos, &lit: &bool| if lit { WHITE } else { BLACK };
+    let rows = vec![vec![true, false, true], vec![false, true, false]];
+    assert_eq!(Image::from_rows(&rows, colour).unwrap(), checkerboard());
+    let flat = [true, false, true, false, true, false];
+    assert_eq!(Image::from_flat(&flat, 3, colour).unwrap(), checkerboard());
+
+    let ragged = vec![vec![true, false], vec![true]];
+    let err = Image::from_rows(&ragged, colour).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "image: row 2 has 1 cells, but the first has 2"
+    );
+    assert!(Image::from_flat(&flat, 4, colour).is_err());
+    assert!(Image::from_flat(&flat, 0, colour).is_err());
+}
+
+#[test]
+fn scales_each_pixel_into_a_square() {
+    let scaled = checkerboard().scaled(2);
+    assert_eq!((scaled.width(), scaled.height()), (6, 4));
+    assert_eq!(scaled.pixels[Pos::new(1, 1)], WHITE);
+    assert_eq!(scaled.pixels[Pos::new(1, 2)], BLACK);
+    assert_eq!(scaled.pixels[Pos::new(3, 3)], WHITE);
+}
+
+#[test]
+fn blends_between_colours() {
+    assert_eq!(blend(BLACK, WHITE, 0, 4), BLACK);
+    assert_eq!(blend(BLACK, WHITE, 1, 4), [63, 63, 63]);
+    assert_eq!(blend([0, 100, 200], [200, 100, 0], 1, 2), [100, 100, 100]);
+    assert_eq!(blend(BLACK, WHITE, 9, 4), WHITE);
+}
+
+#[test]
+fn picks_the_format_from_the_extension() {
+    let dir = std::env::temp_dir().join(format!("common-image-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let ppm = dir.join("board.ppm");
+    checkerboard().save(&ppm).unwrap();
+    assert!(std::fs::read(&ppm).unwrap().starts_with(b"P6\n3 2\n"));
+
+    assert!(checkerboard().save(&dir.join("board.gif")).is_err());
+    let png = checkerboard().save(&dir.join("board.png"));
+    match cfg!(feature = "png") {
+        true => assert!(std::fs::read(dir.join("board.png"))
+            .unwrap()
+            .starts_with(b"\x89PNG")),
+        false => assert!(png.unwrap_err().to_string().contains("`png` feature")),
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn takes_the_image_flag_out_of_the_arguments() {
+    let args = ["input.txt", "--image", "out.png", "b"].map(str::to_string);
+    let (path, rest) = extract_image_path(args.into_iter()).unwrap();
+    assert_eq!(path.as_deref(), Some(Path::new("out.png")));
+    assert_eq!(rest, ["input.txt", "b"]);
+    assert!(extract_image_path(["--image".to_string()].into_iter()).is_err());
+
+    let path = Path::new("pictures/out.png");
+    assert_eq!(
+        variant_path(path, Variant::B),
+        Path::new("pictures/out-b.png")
+    );
+}
@@ -0,0 +1,37 @@
+use common::Normalize;
+
+fn lines(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn strips_what_other_editors_add() {
+    let saved = lines(&["\u{feff}$ cd /\r", "$ ls  \r", "", "\r", "  ", ""]);
+    assert_eq!(
+        Normalize::default().apply(saved.clone()),
+        lines(&["$ cd /", "$ ls  "])
+    );
+    assert_eq!(
+        Normalize {
+            trim_trailing_whitespace: true
+        }
+        .apply(saved),
+        lines(&["$ cd /", "$ ls"])
+    );
+}
+
+#[test]
+fn keeps_leading_and_inner_blank_lines_where_they_are() {
+    // Leading whitespace and blank lines before the end can be part of the input, and removing
+    // them would throw the line numbers in parse errors off.
+    let input = lines(&["", "    [D]", "", "move 1 from 2 to 1", "", ""]);
+    assert_eq!(
+        Normalize {
+            trim_trailing_whitespace: true
+        }
+        .apply(input),
+        lines(&["", "    [D]", "", "move 1 from 2 to 1"])
+    );
+    assert!(Normalize::default().apply(lines(&["", " "])).is_empty());
+    assert!(Normalize::default().apply(Vec::new()).is_empty());
+}
@@ -0,0 +1,59 @@
+use common::blocks;
+use common::parser::{self, Cursor, PResult, Record};
+
+fn lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
+
+fn point(c: &mut Cursor) -> PResult<(i32, i32)> {
+    let x = c.integer()?;
+    c.literal(",")?;
+    Ok((x, c.integer()?))
+}
+
+#[test]
+fn parses_separated_lists_regardless_of_spacing() {
+    let spaced = parser::line(0, "498,4 -> 498,6 -> -1,+2", |c| c.separated("->", point));
+    let packed = parser::line(0, "498,4->498,6->-1,+2", |c| c.separated("->", point));
+    assert_eq!(spaced, Ok(vec![(498, 4), (498, 6), (-1, 2)]));
+    assert_eq!(spaced, packed);
+}
+
+#[test]
+fn reports_where_a_line_went_wrong() {
+    let err = parser::line(4, "498,4 -> 498;6", |c| c.separated("->", point)).unwrap_err();
+    assert_eq!(err.message, "parsing: expected `,`");
+    assert_eq!((err.line, err.column), (5, Some(13)));
+
+    let err = parser::line(0, "1,2 3", point).unwrap_err();
+    assert_eq!(err.message, "parsing: unexpected trailing text");
+    assert_eq!((err.column, err.width), (Some(5), 1));
+
+    let err = parser::line(0, "300", |c| c.integer::<u8>()).unwrap_err();
+    assert_eq!(err.message, "parsing: `300` is out of range");
+    assert_eq!((err.column, err.width), (Some(1), 3));
+
+    let err = parser::line(0, "x", |c| c.one_of(&[("+", 1), ("*", 2)])).unwrap_err();
+    assert_eq!(err.message, "parsing: expected one of `+`, `*`");
+}
+
+#[test]
+fn parses_fields_of_a_record() {
+    let input = lines("Name: x\nSize: 3, 4\n\nName: y");
+    let blocks = blocks(&input).collect::<Vec<_>>();
+
+    let mut first = Record::new(&blocks[0]);
+    assert_eq!(first.field("Name", |c| c.word()), Ok("x"));
+    let size = first.field("Size", |c| c.separated(",", |c| c.integer::<u32>()));
+    assert_eq!(size, Ok(vec![3, 4]));
+    assert!(first.end().is_ok());
+
+    let mut second = Record::new(&blocks[1]);
+    let err = second.field("Size", |c| c.word()).unwrap_err();
+    assert_eq!(err.message, "parsing: expected `Size`");
+    assert_eq!((err.line, err.column), (4, Some(1)));
+
+    // The failed line still counts as read, so there's nothing left.
+    let err = second.field("Size", |c| c.word()).unwrap_err();
+    assert_eq!(err.message, "parsing: record ended early");
+}
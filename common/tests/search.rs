@@ -0,0 +1,98 @@
+use common::grid::{Grid, Pos};
+use common::search::{astar, bfs, dijkstra};
+
+// A maze where `#` is a wall and each open cell costs its digit to enter.
+fn maze() -> Grid<Option<u32>> {
+    let rows = ["1111#", "1#91#", "1#11#", "1###1", "11111"];
+    Grid::parse(&rows, |_, ch| Ok(ch.to_digit(10))).unwrap()
+}
+
+fn steps(grid: &Grid<Option<u32>>, pos: Pos) -> Vec<Pos> {
+    grid.neighbours4(pos)
+        .filter(|&next| grid[next].is_some())
+        .collect()
+}
+
+fn weighted(grid: &Grid<Option<u32>>, pos: Pos) -> Vec<(Pos, u32)> {
+    steps(grid, pos)
+        .into_iter()
+        .map(|next| (next, grid[next].unwrap()))
+        .collect()
+}
+
+fn manhattan(from: Pos, to: Pos) -> u32 {
+    (from.row.abs_diff(to.row) + from.col.abs_diff(to.col)) as u32
+}
+
+#[test]
+fn bfs_finds_the_fewest_steps_and_the_path() {
+    let grid = maze();
+    let (from, to) = (Pos::new(0, 0), Pos::new(2, 3));
+    let paths = bfs([from], |&pos| steps(&grid, pos), |&pos| pos == to);
+    assert_eq!(paths.goal(), Some(&to));
+    assert_eq!(paths.goal_cost(), Some(5));
+
+    let path = paths.path().unwrap();
+    assert_eq!(
+        (path.first(), path.last(), path.len()),
+        (Some(&from), Some(&to), 6)
+    );
+    for pair in path.windows(2) {
+        assert_eq!(manhattan(pair[0], pair[1]), 1);
+    }
+}
+
+#[test]
+fn dijkstra_avoids_costly_cells() {
+    let grid = maze();
+    let (from, to) = (Pos::new(0, 0), Pos::new(2, 2));
+    let fewest = bfs([from], |&pos| steps(&grid, pos), |&pos| pos == to);
+    assert_eq!(fewest.goal_cost(), Some(4));
+
+    // Going round by the right takes more steps, but avoids the 9.
+    let cheapest = dijkstra([from], |&pos| weighted(&grid, pos), |&pos| pos == to);
+    assert_eq!(cheapest.goal_cost(), Some(6));
+    assert!(!cheapest.path().unwrap().contains(&Pos::new(1, 2)));
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    let grid = maze();
+    let from = Pos::new(0, 0);
+    for to in grid.positions().filter(|&pos| grid[pos].is_some()) {
+        let expected = dijkstra([from], |&pos| weighted(&grid, pos), |&pos| pos == to);
+        let found = astar(
+            [from],
+            |&pos| weighted(&grid, pos),
+            |&pos| manhattan(pos, to),
+            |&pos| pos == to,
+        );
+        assert_eq!(found.goal_cost(), expected.goal_cost(), "to {:?}", to);
+    }
+}
+
+#[test]
+fn searches_from_several_starts() {
+    let grid = maze();
+    let starts = [Pos::new(0, 0), Pos::new(4, 4)];
+    let paths = bfs(starts, |&pos| steps(&grid, pos), |_| false);
+    assert_eq!(paths.goal(), None);
+    assert_eq!(paths.cost(&Pos::new(4, 1)), Some(3));
+    assert_eq!(paths.cost(&Pos::new(4, 0)), Some(4));
+    assert_eq!(paths.path_to(&Pos::new(4, 2)).unwrap()[0], Pos::new(4, 4));
+    assert_eq!(paths.reached().count(), 17);
+}
+
+#[test]
+fn reports_unreachable_goals() {
+    let grid = maze();
+    let wall = Pos::new(0, 4);
+    let paths = dijkstra(
+        [Pos::new(0, 0)],
+        |&pos| weighted(&grid, pos),
+        |&pos| pos == wall,
+    );
+    assert_eq!(paths.goal_cost(), None);
+    assert_eq!(paths.path(), None);
+    assert_eq!(paths.path_to(&wall), None);
+}
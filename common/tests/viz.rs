@@ -0,0 +1,104 @@
+use anyhow::Result;
+use common::viz::{play, Key, Playback, Played, Scene, MAX_FPS};
+use std::sync::mpsc;
+
+// Counts up to |end|, a step at a time.
+struct Counter {
+    count: usize,
+    end: usize,
+}
+
+impl Scene for Counter {
+    fn step(&mut self) -> Result<bool> {
+        if self.count == self.end {
+            return Ok(false);
+        }
+        self.count += 1;
+        Ok(true)
+    }
+
+    fn frame(&self) -> String {
+        format!("count {}", self.count)
+    }
+}
+
+fn fast(skip: usize, paused: bool) -> Playback {
+    Playback {
+        fps: MAX_FPS,
+        skip,
+        paused,
+    }
+}
+
+// Play a |Counter| to |end| with |keys| already pressed, returning what was drawn.
+fn play_counter(end: usize, playback: Playback, keys: &[Key]) -> (Played, String) {
+    let (sender, receiver) = mpsc::channel();
+    for key in keys {
+        sender.send(*key).unwrap();
+    }
+    drop(sender);
+    let mut out = Vec::new();
+    let mut scene = Counter { count: 0, end };
+    let played = play(&mut scene, playback, &receiver, &mut out).unwrap();
+    (played, String::from_utf8(out).unwrap())
+}
+
+fn args(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split_whitespace().map(str::to_string)
+}
+
+#[test]
+fn extracts_animation_flags() {
+    let (playback, rest) = Playback::extract(args("input.txt --fps 5 a --skip 3")).unwrap();
+    assert_eq!(
+        playback,
+        Some(Playback {
+            fps: 5,
+            skip: 3,
+            paused: false
+        })
+    );
+    assert_eq!(rest, ["input.txt", "a"]);
+
+    let (playback, rest) = Playback::extract(args("--format json")).unwrap();
+    assert_eq!((playback, rest.len()), (None, 2));
+    assert!(Playback::extract(args("--fps 0")).is_err());
+    assert!(Playback::extract(args("--skip")).is_err());
+}
+
+#[test]
+fn plays_to_the_end_drawing_every_frame() {
+    // Only the step after the last one shows that the scene is done, so it gets a frame too.
+    let (played, out) = play_counter(5, fast(0, false), &[]);
+    assert_eq!(
+        played,
+        Played {
+            steps: 5,
+            frames: 7,
+            finished: true
+        }
+    );
+    assert!(out.starts_with("\x1b[Hcount 0\x1b[K\n"));
+    assert!(out.contains("count 5") && out.contains("done"));
+}
+
+#[test]
+fn skips_frames_between_those_drawn() {
+    let (played, out) = play_counter(10, fast(3, false), &[]);
+    assert_eq!((played.steps, played.frames), (10, 4));
+    let counts: Vec<_> = out.matches("count ").map(|_| ()).collect();
+    assert_eq!(counts.len(), 4);
+    assert!(out.contains("count 8") && !out.contains("count 7"));
+}
+
+#[test]
+fn steps_while_paused_and_stops_on_quit() {
+    let keys = [Key::Step, Key::Step, Key::Quit];
+    let (played, out) = play_counter(100, fast(1, true), &keys);
+    assert_eq!((played.steps, played.finished), (4, false));
+    assert!(out.contains("count 4") && out.contains("paused"));
+
+    // Without any keys left to unpause it, playback carries on by itself.
+    let (played, _) = play_counter(100, fast(0, true), &[Key::Step]);
+    assert_eq!((played.steps, played.finished), (100, true));
+}
@@ -0,0 +1,10 @@
+#![no_main]
+
+use common::{read_lines, DynSolver, Variant};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(lines) = read_lines(data) {
+        let _ = y2022_day_11::Day11.solve_each(lines, &Variant::ALL);
+    }
+});